@@ -0,0 +1,245 @@
+use crate::days::{Day, DayRun};
+use crate::input::InputArgs;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Median changes within this many percent are reported as noise.
+const NOISE_PERCENT: f64 = 5.0;
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Untimed runs before sampling starts
+    #[arg(long, default_value_t = 3)]
+    warmup: u32,
+    /// Maximum number of timed runs per day
+    #[arg(long, default_value_t = 50)]
+    iterations: u32,
+    /// Stop sampling a day once this many seconds were spent on it
+    #[arg(long, default_value = "10", value_parser = parse_seconds)]
+    max_time: Duration,
+    /// Baseline file to compare against
+    #[arg(long, default_value = "bench-baseline.json")]
+    baseline: PathBuf,
+    /// Overwrite the baseline with this run's results
+    #[arg(long)]
+    save: bool,
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{}` is not a number of seconds", value))?;
+    if seconds <= 0.0 {
+        return Err(format!("`{}` is not a positive number of seconds", value));
+    }
+    // rejects NaN, infinity and anything too long for a `Duration`
+    Duration::try_from_secs_f64(seconds).map_err(|err| format!("`{}`: {}", value, err))
+}
+
+/// Summary of the samples of one phase, in nanoseconds.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub stddev: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let n = nanos.len();
+        let median = if n.is_multiple_of(2) {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+        } else {
+            nanos[n / 2]
+        };
+        let mean = nanos.iter().sum::<u64>() as f64 / n as f64;
+        let variance = if n > 1 {
+            nanos
+                .iter()
+                .map(|&x| (x as f64 - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Stats {
+            min: nanos[0],
+            median,
+            mean: mean.round() as u64,
+            stddev: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+/// Phase name (`parse`, `part1`, `part2`) to its statistics.
+type DayStats = BTreeMap<String, Stats>;
+
+#[derive(Serialize, Deserialize, Default)]
+struct Baseline {
+    days: BTreeMap<String, DayStats>,
+}
+
+fn sample(day: &Day, input: &str, args: &BenchArgs) -> Result<DayStats, Box<dyn Error>> {
+    for _ in 0..args.warmup {
+        (day.run)(input, None).map_err(|err| err as Box<dyn Error>)?;
+    }
+
+    let budget = args.max_time;
+    let start = Instant::now();
+    let mut runs: Vec<DayRun> = Vec::new();
+    while runs.len() < args.iterations.max(1) as usize
+        && (runs.is_empty() || start.elapsed() < budget)
+    {
+        runs.push((day.run)(input, None).map_err(|err| err as Box<dyn Error>)?);
+    }
+
+    let mut stats = DayStats::new();
+    let parse: Vec<Duration> = runs.iter().map(|run| run.parse).collect();
+    stats.insert("parse".to_string(), Stats::from_samples(&parse));
+    for part in 1..=2 {
+        let samples: Vec<Duration> = runs
+            .iter()
+            .flat_map(|run| run.parts.iter().filter(|p| p.part == part))
+            .map(|p| p.duration)
+            .collect();
+        stats.insert(format!("part{}", part), Stats::from_samples(&samples));
+    }
+    Ok(stats)
+}
+
+fn load_baseline(args: &BenchArgs) -> Result<Option<Baseline>, Box<dyn Error>> {
+    match fs::read_to_string(&args.baseline) {
+        Ok(text) => Ok(Some(serde_json::from_str(&text).map_err(|err| {
+            format!("invalid baseline `{}`: {}", args.baseline.display(), err)
+        })?)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("cannot read `{}`: {}", args.baseline.display(), err).into()),
+    }
+}
+
+fn compare(current: &Stats, baseline: Option<&Stats>) -> String {
+    let Some(baseline) = baseline.filter(|b| b.median > 0) else {
+        return "-".to_string();
+    };
+    let change = (current.median as f64 - baseline.median as f64) / baseline.median as f64 * 100.0;
+    let verdict = if change > NOISE_PERCENT {
+        "regression"
+    } else if change < -NOISE_PERCENT {
+        "improvement"
+    } else {
+        "unchanged"
+    };
+    format!("{:+.1}% {}", change, verdict)
+}
+
+fn nanos(value: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(value))
+}
+
+pub fn bench(selected: &[&Day], input: &InputArgs, args: &BenchArgs) -> Result<(), Box<dyn Error>> {
+    let baseline = load_baseline(args)?;
+    let mut results = Baseline::default();
+
+    println!(
+        "{:<4} {:<6} {:>12} {:>12} {:>12} {:>12}  vs baseline",
+        "Day", "Phase", "Min", "Median", "Mean", "Stddev"
+    );
+    for day in selected {
+        let text = input.read(day.number)?;
+        let key = format!("{:02}", day.number);
+        let stats = sample(day, &text, args)?;
+        let previous = baseline.as_ref().and_then(|b| b.days.get(&key));
+        for (phase, current) in &stats {
+            println!(
+                "{:<4} {:<6} {:>12} {:>12} {:>12} {:>12}  {}",
+                key,
+                phase,
+                nanos(current.min),
+                nanos(current.median),
+                nanos(current.mean),
+                nanos(current.stddev),
+                compare(current, previous.and_then(|p| p.get(phase))),
+            );
+        }
+        results.days.insert(key, stats);
+    }
+
+    if args.save {
+        // keep the recorded days that were not part of this run
+        let mut merged = baseline.unwrap_or_default();
+        merged.days.extend(results.days);
+        fs::write(
+            &args.baseline,
+            serde_json::to_string_pretty(&merged)? + "\n",
+        )?;
+        println!("\nbaseline saved to `{}`", args.baseline.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn max_time_is_a_finite_positive_number_of_seconds() {
+        assert_eq!(parse_seconds("0.5"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_seconds("10"), Ok(Duration::from_secs(10)));
+        for bad in ["0", "-1", "NaN", "inf", "1e30", "ten"] {
+            assert!(parse_seconds(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn stats_of_odd_sample_count() {
+        let stats = Stats::from_samples(&ms(&[3, 1, 2]));
+        assert_eq!(stats.min, 1_000_000);
+        assert_eq!(stats.median, 2_000_000);
+        assert_eq!(stats.mean, 2_000_000);
+        assert_eq!(stats.stddev, 1_000_000);
+    }
+
+    #[test]
+    fn stats_of_even_sample_count() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, 2_500_000);
+        assert_eq!(stats.mean, 2_500_000);
+    }
+
+    #[test]
+    fn stats_of_single_sample_has_no_spread() {
+        let stats = Stats::from_samples(&ms(&[5]));
+        assert_eq!(
+            (stats.min, stats.median, stats.stddev),
+            (5_000_000, 5_000_000, 0)
+        );
+    }
+
+    #[test]
+    fn compare_classifies_changes() {
+        let at = |median| Stats {
+            min: 0,
+            median,
+            mean: 0,
+            stddev: 0,
+        };
+        assert_eq!(compare(&at(120), Some(&at(100))), "+20.0% regression");
+        assert_eq!(compare(&at(50), Some(&at(100))), "-50.0% improvement");
+        assert_eq!(compare(&at(102), Some(&at(100))), "+2.0% unchanged");
+        assert_eq!(compare(&at(102), None), "-");
+    }
+}
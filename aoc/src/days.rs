@@ -0,0 +1,133 @@
+use crate::memory::{self, Allocations};
+use common::{Answer, ParseError, Solution, SolveError};
+use day01::stream::{SortedLists, DEFAULT_CHUNK};
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&str, Option<u8>) -> Result<DayRun, SolveError>,
+}
+
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub duration: Duration,
+    pub allocations: Option<Allocations>,
+}
+
+pub struct DayRun {
+    pub parse: Duration,
+    pub parse_allocations: Option<Allocations>,
+    pub parts: Vec<PartRun>,
+}
+
+/// Times the requested parts, or both of them.
+fn solve_parts<E>(
+    part: Option<u8>,
+    mut solve: impl FnMut(u8) -> Result<Answer, E>,
+) -> Result<Vec<PartRun>, E> {
+    [1, 2]
+        .into_iter()
+        .filter(|&number| part.is_none_or(|p| p == number))
+        .map(|number| {
+            let start = Instant::now();
+            let (answer, allocations) = memory::measure(|| solve(number));
+            Ok(PartRun {
+                part: number,
+                answer: answer?,
+                duration: start.elapsed(),
+                allocations,
+            })
+        })
+        .collect()
+}
+
+/// Parses the input once and solves the requested parts against the parsed model.
+fn run<S: Solution>(input: &str, part: Option<u8>) -> Result<DayRun, SolveError> {
+    let start = Instant::now();
+    let (parsed, parse_allocations) = memory::measure(|| S::parse(input));
+    let parse = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = solve_parts(part, |number| match number {
+        1 => S::part1(&parsed),
+        _ => S::part2(&parsed),
+    })?;
+    Ok(DayRun {
+        parse,
+        parse_allocations,
+        parts,
+    })
+}
+
+pub type Stream = fn(&mut dyn BufRead, Option<u8>) -> Result<DayRun, SolveError>;
+
+/// Day 1 read a line at a time, its lists sorted through temporary files;
+/// reading and sorting count as parsing.
+fn stream_day01(reader: &mut dyn BufRead, part: Option<u8>) -> Result<DayRun, SolveError> {
+    let start = Instant::now();
+    let (lists, parse_allocations) = memory::measure(|| SortedLists::read(reader, DEFAULT_CHUNK));
+    let parse = start.elapsed();
+    let lists = lists?;
+
+    let parts = solve_parts(part, |number| {
+        match number {
+            1 => lists.distance(),
+            _ => lists.similarity(),
+        }
+        .map(Answer::from)
+    })?;
+    Ok(DayRun {
+        parse,
+        parse_allocations,
+        parts,
+    })
+}
+
+/// Days that can also be solved from a reader in bounded memory.
+pub fn stream(number: u8) -> Option<Stream> {
+    match number {
+        1 => Some(stream_day01),
+        _ => None,
+    }
+}
+
+/// Turns a whole input into a report of what is wrong with each line.
+pub type Explain = fn(&str) -> Result<String, ParseError>;
+
+/// Days that can explain their input line by line.
+pub fn explain(number: u8) -> Option<Explain> {
+    match number {
+        2 => Some(day02::analysis::explain),
+        _ => None,
+    }
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        run: run::<S>,
+    }
+}
+
+pub const DAYS: [Day; 14] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,357 @@
+use crate::generate::{self, Size};
+use clap::Args;
+use common::{Answer, Reference, Solution, SolveError};
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Inputs tried while shrinking one failure, to bound the time it takes.
+const MAX_SHRINK_ATTEMPTS: usize = 20_000;
+
+#[derive(Args)]
+pub struct DiffArgs {
+    /// Number of random inputs compared for every day
+    #[arg(long, default_value_t = 100)]
+    cases: u64,
+    /// Seed of the first input, the next ones count up from it; picked at
+    /// random and printed when omitted
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Sizes default to 10, small enough for the reference solvers
+    #[command(flatten)]
+    size: Size,
+}
+
+/// An answer, or why there is none: an error or a panic.
+type Attempt = Result<Answer, String>;
+
+type Solve<S> = fn(&<S as Solution>::Input) -> Result<Answer, SolveError>;
+
+type Slow<S> = fn(&<S as Solution>::Input) -> Answer;
+
+struct Difference {
+    part: u8,
+    fast: Attempt,
+    reference: Attempt,
+}
+
+enum Outcome {
+    /// The input does not parse or breaks a promise of the puzzle.
+    Invalid,
+    Agree,
+    Disagree(Vec<Difference>),
+}
+
+impl Outcome {
+    fn disagrees(&self) -> bool {
+        matches!(self, Outcome::Disagree(_))
+    }
+}
+
+struct Check {
+    number: u8,
+    compare: fn(&str) -> Outcome,
+}
+
+fn attempt(solve: impl FnOnce() -> Result<Answer, SolveError>) -> Attempt {
+    let answer = panic::catch_unwind(AssertUnwindSafe(solve)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        format!("panic ({})", message)
+    })?;
+    answer.map_err(|err| format!("error ({})", err))
+}
+
+/// Solves both parts of `input` with the day's solution and its reference.
+fn compare<S: Reference>(input: &str) -> Outcome {
+    let Ok(parsed) = S::parse(input) else {
+        return Outcome::Invalid;
+    };
+    if !S::is_valid(&parsed) {
+        return Outcome::Invalid;
+    }
+    let parts: [(u8, Solve<S>, Slow<S>); 2] = [
+        (1, S::part1, S::reference_part1),
+        (2, S::part2, S::reference_part2),
+    ];
+    let differences: Vec<Difference> = parts
+        .into_iter()
+        .filter_map(|(part, fast, reference)| {
+            let fast = attempt(|| fast(&parsed));
+            let reference = attempt(|| Ok(reference(&parsed)));
+            (fast != reference).then_some(Difference {
+                part,
+                fast,
+                reference,
+            })
+        })
+        .collect();
+    if differences.is_empty() {
+        Outcome::Agree
+    } else {
+        Outcome::Disagree(differences)
+    }
+}
+
+const fn check<S: Reference>() -> Check {
+    Check {
+        number: S::DAY,
+        compare: compare::<S>,
+    }
+}
+
+/// Days that have a reference solver.
+const CHECKS: [Check; 4] = [
+    check::<day06::Day06>(),
+    check::<day11::Day11>(),
+    check::<day12::Day12>(),
+    check::<day13::Day13>(),
+];
+
+/// The lines with a newline after each of them.
+fn join_lines(lines: &[&str]) -> String {
+    lines.iter().flat_map(|line| [*line, "\n"]).collect()
+}
+
+/// Removes each run of `len` consecutive units in turn.
+fn without_runs(units: &[&str], len: usize, join: impl Fn(&[&str]) -> String) -> Vec<String> {
+    (0..=units.len() - len)
+        .step_by(len)
+        .map(|start| {
+            let kept: Vec<&str> = units[..start]
+                .iter()
+                .chain(&units[start + len..])
+                .copied()
+                .collect();
+            join(&kept)
+        })
+        .collect()
+}
+
+/// Removes halves, then quarters, and so on down to single units.
+fn removals(units: &[&str], join: impl Fn(&[&str]) -> String) -> Vec<String> {
+    let mut candidates = Vec::new();
+    let mut len = units.len() / 2;
+    while len > 0 {
+        candidates.extend(without_runs(units, len, &join));
+        len /= 2;
+    }
+    candidates
+}
+
+/// Smaller versions of `text`, the most aggressive first: without blocks,
+/// lines, words or grid columns, or with a number made smaller. Every
+/// candidate is shorter, or as long with lower digits, so shrinking ends.
+fn candidates(text: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+
+    let blocks: Vec<&str> = text.trim_end().split("\n\n").collect();
+    if blocks.len() > 1 {
+        candidates.extend(removals(&blocks, |kept| kept.join("\n\n") + "\n"));
+    }
+
+    let lines: Vec<&str> = text.lines().collect();
+    candidates.extend(removals(&lines, join_lines));
+
+    for (i, line) in lines.iter().enumerate() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.len() > 1 {
+            for kept in removals(&words, |kept| kept.join(" ")) {
+                let mut lines = lines.clone();
+                lines[i] = &kept;
+                candidates.push(join_lines(&lines));
+            }
+        }
+    }
+
+    let width = lines.first().map_or(0, |line| line.len());
+    let is_grid = width > 1
+        && lines
+            .iter()
+            .all(|line| line.len() == width && line.is_ascii());
+    if is_grid {
+        for col in 0..width {
+            candidates.push(
+                lines
+                    .iter()
+                    .flat_map(|line| [&line[..col], &line[col + 1..], "\n"])
+                    .collect(),
+            );
+        }
+    }
+
+    let mut start = 0;
+    while let Some(offset) = text[start..].find(|c: char| c.is_ascii_digit()) {
+        let begin = start + offset;
+        let end = text[begin..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(text.len(), |len| begin + len);
+        if let Ok(value) = text[begin..end].parse::<u64>() {
+            let mut smaller = vec![0, value / 2, value.saturating_sub(1)];
+            smaller.dedup();
+            for lower in smaller.into_iter().filter(|&lower| lower < value) {
+                candidates.push(format!("{}{}{}", &text[..begin], lower, &text[end..]));
+            }
+        }
+        start = end;
+    }
+    candidates
+}
+
+/// Makes `input` smaller for as long as it keeps failing.
+fn shrink(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut current = input.to_string();
+    let mut attempts = 0;
+    while attempts < MAX_SHRINK_ATTEMPTS {
+        let smaller = candidates(&current)
+            .into_iter()
+            .take(MAX_SHRINK_ATTEMPTS - attempts)
+            .find(|candidate| {
+                attempts += 1;
+                fails(candidate)
+            });
+        match smaller {
+            Some(smaller) => current = smaller,
+            None => break,
+        }
+    }
+    current
+}
+
+fn show(attempt: &Attempt) -> String {
+    match attempt {
+        Ok(answer) => answer.to_string(),
+        Err(message) => message.clone(),
+    }
+}
+
+/// Compares `cases` generated inputs; on the first one that disagrees,
+/// prints it shrunk and returns `false`.
+fn check_day(check: &Check, args: &DiffArgs, seed: u64, size: &Size) -> Result<bool, String> {
+    for seed in (0..args.cases).map(|case| seed.wrapping_add(case)) {
+        let input = generate::input(check.number, seed, size)?;
+        if !(check.compare)(&input).disagrees() {
+            continue;
+        }
+        let smallest = shrink(&input, |input| (check.compare)(input).disagrees());
+        println!(
+            "Day {:02}: seed {} disagrees, smallest failing input:\n{}",
+            check.number, seed, smallest
+        );
+        if let Outcome::Disagree(differences) = (check.compare)(&smallest) {
+            for difference in differences {
+                println!(
+                    "  part {}: solution {}, reference {}",
+                    difference.part,
+                    show(&difference.fast),
+                    show(&difference.reference)
+                );
+            }
+        }
+        return Ok(false);
+    }
+    println!("Day {:02}: {} inputs agree", check.number, args.cases);
+    Ok(true)
+}
+
+pub fn diff(days: &[u8], args: &DiffArgs) -> Result<(), Box<dyn Error>> {
+    let checks: Vec<&Check> = CHECKS
+        .iter()
+        .filter(|check| days.contains(&check.number))
+        .collect();
+    if checks.is_empty() {
+        return Err("no reference solver for the selected days".into());
+    }
+    let seed = args.seed.unwrap_or_else(rand::random);
+    if args.seed.is_none() {
+        println!("seed: {}", seed);
+    }
+    let size = Size {
+        count: args.size.count.or(Some(10)),
+        width: args.size.width.or(Some(10)),
+        height: args.size.height.or(Some(10)),
+    };
+
+    // failures are reported with the input, the default hook would only add
+    // noise for every panic met while shrinking
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results: Result<Vec<bool>, String> = checks
+        .iter()
+        .map(|check| check_day(check, args, seed, &size))
+        .collect();
+    panic::set_hook(hook);
+
+    let failed = results?.into_iter().filter(|&agree| !agree).count();
+    if failed > 0 {
+        return Err(format!("{} day(s) disagree with their reference", failed).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_agree_on_generated_inputs() {
+        let size = Size {
+            count: Some(6),
+            width: Some(8),
+            height: Some(8),
+        };
+        for check in &CHECKS {
+            for seed in 0..5 {
+                let input = generate::input(check.number, seed, &size).unwrap();
+                match (check.compare)(&input) {
+                    Outcome::Agree => {}
+                    Outcome::Invalid => panic!("day {:02}: invalid input\n{}", check.number, input),
+                    Outcome::Disagree(differences) => panic!(
+                        "day {:02}, seed {}, part {}\n{}",
+                        check.number, seed, differences[0].part, input
+                    ),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_inputs_are_not_compared() {
+        let compare = compare::<day06::Day06>;
+        assert!(matches!(compare("..\n"), Outcome::Invalid));
+        assert!(matches!(
+            compare(".#..\n...#\n#^..\n..#.\n"),
+            Outcome::Invalid
+        ));
+        assert!(matches!(compare(".#.\n...\n.^.\n"), Outcome::Agree));
+    }
+
+    #[test]
+    fn shrinks_lines_words_and_numbers() {
+        let fails = |text: &str| text.split_whitespace().any(|word| word.parse() == Ok(40));
+        assert_eq!(shrink("1 2\n3 40\n5 6\n", fails), "40\n");
+        let fails = |text: &str| {
+            text.split_whitespace()
+                .any(|word| word.parse::<u32>().is_ok_and(|n| n >= 7))
+        };
+        assert_eq!(shrink("1 2\n3 40\n5 6\n", fails), "7\n");
+    }
+
+    #[test]
+    fn shrinks_grids_and_blocks() {
+        assert_eq!(shrink("...\n.#.\n...\n", |text| text.contains('#')), "#\n");
+        let fails = |text: &str| text.contains("b=2");
+        assert_eq!(shrink("a=1\nb=2\n\nc=3\nb=2\n\nd=4\n", fails), "b=2\n");
+    }
+
+    #[test]
+    fn panics_and_errors_count_as_answers() {
+        let panicked = attempt(|| panic!("overflow"));
+        assert_eq!(show(&panicked), "panic (overflow)");
+        let failed = attempt(|| Err("too large".into()));
+        assert_eq!(show(&failed), "error (too large)");
+        assert_ne!(panicked, failed);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::input;
+use clap::Args;
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (input fetch)");
+/// Environment variable holding the `session` cookie of a logged-in browser.
+const SESSION_VAR: &str = "AOC_SESSION";
+/// Requests are spaced at least this far apart to go easy on the server.
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Puzzle year
+    #[arg(long, default_value_t = 2024)]
+    year: u16,
+    /// File holding `session = "..."`, used when `AOC_SESSION` is not set
+    #[arg(long, default_value = "aoc.toml")]
+    config: PathBuf,
+    #[arg(long, default_value = BASE_URL, hide = true)]
+    base_url: String,
+}
+
+#[derive(Deserialize)]
+struct Config {
+    session: String,
+}
+
+/// Reads the session token from `AOC_SESSION`, or else from the config file.
+fn session(config: &Path) -> Result<String, Box<dyn Error>> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session.trim().to_string());
+    }
+    match fs::read_to_string(config) {
+        Ok(text) => {
+            let config: Config = toml::from_str(&text)
+                .map_err(|err| format!("invalid `{}`: {}", config.display(), err))?;
+            Ok(config.session.trim().to_string())
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(format!(
+            "no session token: set {} or add `session = \"...\"` to `{}`",
+            SESSION_VAR,
+            config.display()
+        )
+        .into()),
+        Err(err) => Err(format!("cannot read `{}`: {}", config.display(), err).into()),
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String, min_interval: Duration) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            min_interval,
+            last_request: None,
+        }
+    }
+
+    fn wait_turn(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    pub fn input(&mut self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        self.wait_turn();
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => {
+                Err(format!("day {} of {} is not unlocked yet", day, year).into())
+            }
+            Err(ureq::Error::Status(code @ (400 | 401 | 403 | 500), _)) => Err(format!(
+                "the server rejected the session token (status {}); it may have expired",
+                code
+            )
+            .into()),
+            Err(err) => Err(format!("cannot fetch `{}`: {}", url, err).into()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Downloaded,
+    Cached,
+}
+
+/// Stores the day's input as `input.txt` in `dir`, unless it is already there.
+pub fn fetch_day(
+    client: &mut Client,
+    year: u16,
+    day: u8,
+    dir: &Path,
+) -> Result<Outcome, Box<dyn Error>> {
+    let path = dir.join("input.txt");
+    if path.exists() {
+        return Ok(Outcome::Cached);
+    }
+    let input = client.input(year, day)?;
+    fs::create_dir_all(dir)?;
+    fs::write(&path, input).map_err(|err| format!("cannot write `{}`: {}", path.display(), err))?;
+    Ok(Outcome::Downloaded)
+}
+
+pub fn fetch(days: &[u8], args: &FetchArgs) -> Result<(), Box<dyn Error>> {
+    // only ask for a token when something actually has to be downloaded
+    let mut client = None;
+    for &day in days {
+        let dir = input::day_dir(day);
+        if dir.join("input.txt").exists() {
+            println!("Day {:02}: already downloaded", day);
+            continue;
+        }
+        let client = match &mut client {
+            Some(client) => client,
+            None => client.insert(Client::new(
+                &args.base_url,
+                session(&args.config)?,
+                MIN_INTERVAL,
+            )),
+        };
+        fetch_day(client, args.year, day, &dir)?;
+        println!(
+            "Day {:02}: saved to `{}`",
+            day,
+            dir.join("input.txt").display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// A stand-in for the puzzle site: answers every request with `status`
+    /// and `body`, and reports each request line and cookie it received.
+    fn server(status: u16, body: &'static str) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.strip_prefix("Cookie: ") {
+                        cookie = value.trim().to_string();
+                    }
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send((request.trim().to_string(), cookie)).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    fn client(url: &str, min_interval: Duration) -> Client {
+        Client::new(url, "abc123".to_string(), min_interval)
+    }
+
+    #[test]
+    fn downloads_and_caches_the_input() {
+        let (url, requests) = server(200, "1 2\n3 4\n");
+        let dir = tempfile::tempdir().unwrap();
+        let day_dir = dir.path().join("day07");
+        let mut client = client(&url, Duration::ZERO);
+
+        let outcome = fetch_day(&mut client, 2024, 7, &day_dir).unwrap();
+        assert_eq!(outcome, Outcome::Downloaded);
+        assert_eq!(
+            fs::read_to_string(day_dir.join("input.txt")).unwrap(),
+            "1 2\n3 4\n"
+        );
+        let (request, cookie) = requests.recv().unwrap();
+        assert_eq!(request, "GET /2024/day/7/input HTTP/1.1");
+        assert_eq!(cookie, "session=abc123");
+
+        let outcome = fetch_day(&mut client, 2024, 7, &day_dir).unwrap();
+        assert_eq!(outcome, Outcome::Cached);
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn never_overwrites_an_existing_input() {
+        let (url, requests) = server(200, "new");
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("input.txt"), "old").unwrap();
+
+        let outcome = fetch_day(&mut client(&url, Duration::ZERO), 2024, 1, dir.path()).unwrap();
+        assert_eq!(outcome, Outcome::Cached);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("input.txt")).unwrap(),
+            "old"
+        );
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn spaces_out_requests() {
+        let (url, _requests) = server(200, "x");
+        let mut client = client(&url, Duration::from_millis(300));
+        let start = Instant::now();
+        for _ in 0..3 {
+            client.input(2024, 1).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(600));
+    }
+
+    #[test]
+    fn reports_locked_days_and_bad_sessions() {
+        let (url, _requests) = server(404, "Not Found");
+        let dir = tempfile::tempdir().unwrap();
+        let err = fetch_day(&mut client(&url, Duration::ZERO), 2024, 25, dir.path()).unwrap_err();
+        assert_eq!(err.to_string(), "day 25 of 2024 is not unlocked yet");
+        assert!(!dir.path().join("input.txt").exists());
+
+        let (url, _requests) = server(400, "Bad Request");
+        let err = client(&url, Duration::ZERO).input(2024, 1).unwrap_err();
+        assert!(err.to_string().contains("rejected the session token"));
+    }
+
+    #[test]
+    fn session_comes_from_the_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("aoc.toml");
+        fs::write(&config, "session = \"  from-file \"\n").unwrap();
+        if env::var(SESSION_VAR).is_err() {
+            assert_eq!(session(&config).unwrap(), "from-file");
+            let err = session(&dir.path().join("missing.toml")).unwrap_err();
+            assert!(err.to_string().contains(SESSION_VAR));
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use clap::Args;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng as _, SeedableRng};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+/// A portable generator, so a seed gives the same input on every machine.
+pub type Rng = rand_chacha::ChaCha8Rng;
+
+#[derive(Args)]
+pub struct GenArgs {
+    /// Seed of the random generator, picked at random and printed when omitted
+    #[arg(long)]
+    seed: Option<u64>,
+    #[command(flatten)]
+    size: Size,
+    /// Write `dayNN.txt` files into this directory instead of printing the
+    /// input; needed when several days are generated
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+/// How big a generated input should be. Every day picks its own defaults,
+/// close to the size of the real inputs.
+#[derive(Args, Clone, Copy, Default)]
+pub struct Size {
+    /// Number of lines, stones, machines, robots or disk map digits
+    #[arg(long = "size")]
+    pub count: Option<usize>,
+    /// Width of the grid, or of the room for day 14
+    #[arg(long)]
+    pub width: Option<usize>,
+    /// Height of the grid, or of the room for day 14
+    #[arg(long)]
+    pub height: Option<usize>,
+}
+
+impl Size {
+    fn count(&self, default: usize) -> usize {
+        self.count.unwrap_or(default).max(1)
+    }
+
+    fn width(&self, default: usize) -> usize {
+        self.width.unwrap_or(default).max(1)
+    }
+
+    fn height(&self, default: usize) -> usize {
+        self.height.unwrap_or(default).max(1)
+    }
+}
+
+pub type Generator = fn(&mut Rng, &Size) -> String;
+
+pub fn find(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day01,
+        2 => day02,
+        3 => day03,
+        4 => day04,
+        5 => day05,
+        6 => day06,
+        7 => day07,
+        8 => day08,
+        9 => day09,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        13 => day13,
+        14 => day14,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Generates the input of `day` from `seed`.
+pub fn input(day: u8, seed: u64, size: &Size) -> Result<String, String> {
+    let generator = find(day).ok_or_else(|| format!("day {:02} has no generator", day))?;
+    Ok(generator(&mut Rng::seed_from_u64(seed), size))
+}
+
+pub fn generate(days: &[u8], args: &GenArgs) -> Result<(), Box<dyn Error>> {
+    if days.len() > 1 && args.output.is_none() {
+        return Err("--output is needed to generate several days".into());
+    }
+    let seed = args.seed.unwrap_or_else(rand::random);
+    if args.seed.is_none() {
+        eprintln!("seed: {}", seed);
+    }
+    for &day in days {
+        let input = input(day, seed, &args.size)?;
+        let Some(dir) = &args.output else {
+            print!("{}", input);
+            continue;
+        };
+        let path = dir.join(format!("day{:02}.txt", day));
+        fs::create_dir_all(dir)?;
+        fs::write(&path, input)
+            .map_err(|err| format!("cannot write `{}`: {}", path.display(), err))?;
+        println!("Day {:02}: wrote `{}`", day, path.display());
+    }
+    Ok(())
+}
+
+/// Rows of `width` cells, each produced by `cell` from its row and column.
+fn grid(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    let mut text = String::with_capacity((width + 1) * height);
+    for row in 0..height {
+        text.extend((0..width).map(|col| cell(row, col)));
+        text.push('\n');
+    }
+    text
+}
+
+/// Cells that mostly copy the one on their left or above, so that they form
+/// patches instead of noise. `fresh` draws a new cell, `nudge` changes a copy.
+fn patches(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    mut fresh: impl FnMut(&mut Rng) -> u8,
+    mut nudge: impl FnMut(&mut Rng, u8) -> u8,
+) -> Vec<Vec<u8>> {
+    let mut rows: Vec<Vec<u8>> = Vec::with_capacity(height);
+    for _ in 0..height {
+        let mut cells = Vec::with_capacity(width);
+        for col in 0..width {
+            let left = cells.last().copied();
+            let up = rows.last().map(|above: &Vec<u8>| above[col]);
+            let copied = match (left, up) {
+                (Some(left), Some(up)) => Some(if rng.random_bool(0.5) { left } else { up }),
+                (left, up) => left.or(up),
+            };
+            let cell = match copied {
+                Some(copied) if rng.random_bool(0.85) => nudge(rng, copied),
+                _ => fresh(rng),
+            };
+            cells.push(cell);
+        }
+        rows.push(cells);
+    }
+    rows
+}
+
+/// Two location lists; about a third of the right one repeats ids of the left.
+fn day01(rng: &mut Rng, size: &Size) -> String {
+    let count = size.count(1000);
+    let left: Vec<u32> = (0..count)
+        .map(|_| rng.random_range(10_000..100_000))
+        .collect();
+    left.iter()
+        .map(|&l| {
+            let r = if rng.random_bool(0.3) {
+                *left.choose(rng).unwrap()
+            } else {
+                rng.random_range(10_000..100_000)
+            };
+            format!("{}   {}\n", l, r)
+        })
+        .collect()
+}
+
+/// Safe reports, half of which get one or two levels replaced.
+fn day02(rng: &mut Rng, size: &Size) -> String {
+    (0..size.count(1000))
+        .map(|_| {
+            let len = rng.random_range(5..=8);
+            let increasing = rng.random_bool(0.5);
+            let mut level: i32 = if increasing {
+                rng.random_range(1..=70)
+            } else {
+                rng.random_range(30..=99)
+            };
+            let mut levels = Vec::with_capacity(len);
+            for _ in 0..len {
+                levels.push(level);
+                let step = rng.random_range(1..=3);
+                level += if increasing { step } else { -step };
+            }
+            if rng.random_bool(0.5) {
+                for _ in 0..rng.random_range(1..=2) {
+                    let i = rng.random_range(0..len);
+                    levels[i] = rng.random_range(1..=99);
+                }
+            }
+            let levels: Vec<String> = levels.iter().map(|l| l.to_string()).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+/// Corrupted memory: instructions, broken instructions and noise.
+fn day03(rng: &mut Rng, size: &Size) -> String {
+    const NOISE: &[&str] = &[
+        "!", "@", "#", "$", "%", "^", "&", "*", "(", ")", "[", "]", "{", "}", "<", ">", ",", ";",
+        ":", "'", "?", "/", " ", "+", "-", "_", "~", "select", "from", "where", "why", "who",
+        "how", "when", "what",
+    ];
+    let mut text = String::new();
+    for i in 1..=size.count(700) {
+        let a: u32 = rng.random_range(1..1000);
+        let b: u32 = rng.random_range(1..1000);
+        let token = match rng.random_range(0..10) {
+            0..=3 => format!("mul({},{})", a, b),
+            4 => "do()".to_string(),
+            5 => "don't()".to_string(),
+            6 => [
+                format!("mul({}*", a),
+                format!("mul ( {} , {} )", a, b),
+                format!("mul[{},{}]", a, b),
+                format!("mul({},{}]", a, b),
+                "do_not_mul(".to_string(),
+            ]
+            .choose(rng)
+            .unwrap()
+            .clone(),
+            _ => NOISE.choose(rng).unwrap().to_string(),
+        };
+        text.push_str(&token);
+        if i % 100 == 0 {
+            text.push('\n');
+        }
+    }
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+/// A word search made only of the letters of `XMAS`.
+fn day04(rng: &mut Rng, size: &Size) -> String {
+    grid(size.width(140), size.height(140), |_, _| {
+        *['X', 'M', 'A', 'S'].choose(rng).unwrap()
+    })
+}
+
+/// Rules ordering every pair of pages, and updates of an odd number of
+/// pages, half of them already in order.
+fn day05(rng: &mut Rng, size: &Size) -> String {
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(49);
+
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{}|{}\n", before, after));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut text: String = rules.concat();
+    text.push('\n');
+    for _ in 0..size.count(200) {
+        let len = rng.random_range(2..=11) * 2 + 1;
+        let mut pages: Vec<u32> = order.choose_multiple(rng, len).copied().collect();
+        if rng.random_bool(0.5) {
+            pages.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+        let pages: Vec<String> = pages.iter().map(|p| p.to_string()).collect();
+        text.push_str(&pages.join(","));
+        text.push('\n');
+    }
+    text
+}
+
+/// A lab with scattered obstructions, redrawn until the guard walks out.
+fn day06(rng: &mut Rng, size: &Size) -> String {
+    let (width, height) = (size.width(130), size.height(130));
+    loop {
+        let mut cells: Vec<Vec<char>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.random_bool(0.1) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let (row, col) = (rng.random_range(0..height), rng.random_range(0..width));
+        cells[row][col] = '^';
+        if guard_leaves(&cells, (row, col)) {
+            return grid(width, height, |row, col| cells[row][col]);
+        }
+    }
+}
+
+/// Whether the guard starting up at `start` ever steps off the map.
+fn guard_leaves(cells: &[Vec<char>], start: (usize, usize)) -> bool {
+    let deltas = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let (mut row, mut col) = (start.0 as isize, start.1 as isize);
+    let mut facing = 0;
+    let mut seen = HashSet::new();
+    while seen.insert((row, col, facing)) {
+        let (next_row, next_col) = (row + deltas[facing].0, col + deltas[facing].1);
+        let Some(cell) = cells
+            .get(next_row as usize)
+            .and_then(|cells| cells.get(next_col as usize))
+        else {
+            return true;
+        };
+        if *cell == '#' {
+            facing = (facing + 1) % 4;
+        } else {
+            (row, col) = (next_row, next_col);
+        }
+    }
+    false
+}
+
+/// Equations whose value is what random operators give, or close to it.
+fn day07(rng: &mut Rng, size: &Size) -> String {
+    // keeps every intermediate result of the solvers far from `i64::MAX`
+    const LIMIT: i64 = 10_000_000_000_000;
+    (0..size.count(850))
+        .map(|_| {
+            let numbers: Vec<i64> = (0..rng.random_range(2..=12))
+                .map(|_| rng.random_range(1..1000))
+                .collect();
+            let mut value = numbers[0];
+            for &n in &numbers[1..] {
+                let next = match rng.random_range(0..3) {
+                    0 => value * n,
+                    1 => format!("{}{}", value, n).parse().unwrap(),
+                    _ => value + n,
+                };
+                value = if next < LIMIT { next } else { value + n };
+            }
+            if rng.random_bool(0.5) {
+                value += rng.random_range(1..100);
+            }
+            let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+            format!("{}: {}\n", value, numbers.join(" "))
+        })
+        .collect()
+}
+
+/// A roof with antennas of a few dozen frequencies on distinct cells.
+fn day08(rng: &mut Rng, size: &Size) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let (width, height) = (size.width(50), size.height(50));
+    let count = size.count(200).min(width * height);
+    let used: Vec<u8> = FREQUENCIES
+        .choose_multiple(rng, (count / 4).clamp(1, FREQUENCIES.len()))
+        .copied()
+        .collect();
+    let mut cells = vec!['.'; width * height];
+    for i in rand::seq::index::sample(rng, width * height, count) {
+        cells[i] = *used.choose(rng).unwrap() as char;
+    }
+    grid(width, height, |row, col| cells[row * width + col])
+}
+
+/// A disk map of `count` digits; files are never empty.
+fn day09(rng: &mut Rng, size: &Size) -> String {
+    let mut text: String = (0..size.count(19999))
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.random_range(1..=9)
+            } else {
+                rng.random_range(0..=9)
+            };
+            char::from_digit(digit, 10).unwrap()
+        })
+        .collect();
+    text.push('\n');
+    text
+}
+
+/// A topographic map of slopes, so that trails are long and plentiful.
+fn day10(rng: &mut Rng, size: &Size) -> String {
+    let (width, height) = (size.width(50), size.height(50));
+    let heights = patches(
+        rng,
+        width,
+        height,
+        |rng| rng.random_range(0..=9),
+        |rng, copied| match copied {
+            0 => 1,
+            9 => 8,
+            _ if rng.random_bool(0.5) => copied + 1,
+            _ => copied - 1,
+        },
+    );
+    grid(width, height, |row, col| (b'0' + heights[row][col]) as char)
+}
+
+/// A line of stones, a few of which are small.
+fn day11(rng: &mut Rng, size: &Size) -> String {
+    let stones: Vec<String> = (0..size.count(8))
+        .map(|_| {
+            let stone: u64 = if rng.random_bool(0.25) {
+                rng.random_range(0..10)
+            } else {
+                rng.random_range(10..10_000_000)
+            };
+            stone.to_string()
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+/// A garden of patches of the same plant.
+fn day12(rng: &mut Rng, size: &Size) -> String {
+    let (width, height) = (size.width(140), size.height(140));
+    let plants = patches(
+        rng,
+        width,
+        height,
+        |rng| rng.random_range(b'A'..=b'Z'),
+        |_, copied| copied,
+    );
+    grid(width, height, |row, col| plants[row][col] as char)
+}
+
+/// Claw machines, half of which can win their prize.
+fn day13(rng: &mut Rng, size: &Size) -> String {
+    let machines: Vec<String> = (0..size.count(320))
+        .map(|_| {
+            let (a, b) = loop {
+                let a: (i64, i64) = (rng.random_range(10..100), rng.random_range(10..100));
+                let b: (i64, i64) = (rng.random_range(10..100), rng.random_range(10..100));
+                // the buttons must not move the claw along the same line
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+            let prize = if rng.random_bool(0.5) {
+                let (pa, pb) = (rng.random_range(0..=100), rng.random_range(0..=100));
+                (a.0 * pa + b.0 * pb, a.1 * pa + b.1 * pb)
+            } else {
+                loop {
+                    let prize = (rng.random_range(1000..20000), rng.random_range(1000..20000));
+                    if !winnable(a, b, prize) {
+                        break prize;
+                    }
+                }
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect();
+    machines.join("\n")
+}
+
+/// Whether whole numbers of presses of `a` and `b` reach `prize`.
+fn winnable(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> bool {
+    let det = a.0 * b.1 - a.1 * b.0;
+    let pa = prize.0 * b.1 - prize.1 * b.0;
+    let pb = a.0 * prize.1 - a.1 * prize.0;
+    pa % det == 0 && pb % det == 0 && pa / det >= 0 && pb / det >= 0
+}
+
+/// A swarm of robots anywhere in a room of the given size. Any other room
+/// than the puzzle's is named on a first `size=W,H` line.
+fn day14(rng: &mut Rng, size: &Size) -> String {
+    let (width, height) = (size.width(101), size.height(103));
+    let header = if (width, height) == (101, 103) {
+        String::new()
+    } else {
+        format!("size={},{}\n", width, height)
+    };
+    let robots = (0..size.count(500)).map(|_| {
+        format!(
+            "p={},{} v={},{}\n",
+            rng.random_range(0..width),
+            rng.random_range(0..height),
+            rng.random_range(-99..=99),
+            rng.random_range(-99..=99)
+        )
+    });
+    std::iter::once(header).chain(robots).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use common::Solution;
+
+    fn small() -> Size {
+        Size {
+            count: Some(20),
+            width: Some(12),
+            height: Some(10),
+        }
+    }
+
+    #[test]
+    fn every_day_generates_inputs_it_can_solve() {
+        for day in &DAYS {
+            for seed in 0..3 {
+                let input = input(day.number, seed, &small()).unwrap();
+                if let Err(err) = (day.run)(&input, None) {
+                    panic!("day {:02}, seed {}: {}\n{}", day.number, seed, err, input);
+                }
+            }
+            // enough for answers beyond `i32`, such as day 14's safety
+            // factor; part 1 only, to keep the slower days quick
+            let large = Size {
+                count: Some(5_000),
+                ..small()
+            };
+            let input = input(day.number, 0, &large).unwrap();
+            if let Err(err) = (day.run)(&input, Some(1)) {
+                panic!("day {:02}, large input: {}", day.number, err);
+            }
+        }
+    }
+
+    #[test]
+    fn a_seed_always_gives_the_same_input() {
+        for day in &DAYS {
+            let first = input(day.number, 7, &small()).unwrap();
+            assert_eq!(input(day.number, 7, &small()).unwrap(), first);
+            assert_ne!(input(day.number, 8, &small()).unwrap(), first);
+        }
+    }
+
+    #[test]
+    fn sizes_are_respected() {
+        let size = small();
+        let mut rng = Rng::seed_from_u64(1);
+        assert_eq!(day01(&mut rng, &size).lines().count(), 20);
+        assert_eq!(day09(&mut rng, &size).trim().len(), 20);
+        assert_eq!(day11(&mut rng, &size).split_whitespace().count(), 20);
+        assert_eq!(day13(&mut rng, &size).matches("Prize").count(), 20);
+        let map = day06(&mut rng, &size);
+        assert_eq!(map.lines().count(), 10);
+        assert!(map.lines().all(|line| line.len() == 12));
+        assert_eq!(map.matches('^').count(), 1);
+        let robots = day14(&mut rng, &size);
+        assert_eq!(robots.lines().next(), Some("size=12,10"));
+        assert_eq!(robots.lines().count(), 21);
+        let robots = day14(&mut rng, &Size::default());
+        assert!(robots.starts_with("p="));
+    }
+
+    #[test]
+    fn machines_with_and_without_a_prize() {
+        let text = day13(&mut Rng::seed_from_u64(3), &small());
+        let games = day13::Day13::parse(&text).unwrap();
+        let winnable = text
+            .split("\n\n")
+            .filter(|machine| {
+                let numbers: Vec<i64> = machine
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter_map(|n| n.parse().ok())
+                    .collect();
+                let [ax, ay, bx, by, px, py] = numbers[..] else {
+                    panic!("not a machine: {}", machine);
+                };
+                winnable((ax, ay), (bx, by), (px, py))
+            })
+            .count();
+        assert_eq!(games.len(), 20);
+        assert!(winnable > 0 && winnable < 20);
+    }
+
+    #[test]
+    fn winnable_solves_the_example() {
+        assert!(winnable((94, 34), (22, 67), (8400, 5400)));
+        assert!(!winnable((26, 66), (67, 21), (12748, 12176)));
+    }
+
+    #[test]
+    fn guard_leaves_unless_boxed_in() {
+        let open: Vec<Vec<char>> = [".#.", "...", ".^."]
+            .iter()
+            .map(|row| row.chars().collect())
+            .collect();
+        assert!(guard_leaves(&open, (2, 1)));
+        let boxed: Vec<Vec<char>> = [".#..", "...#", "#^..", "..#."]
+            .iter()
+            .map(|row| row.chars().collect())
+            .collect();
+        assert!(!guard_leaves(&boxed, (2, 1)));
+    }
+}
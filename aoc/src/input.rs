@@ -0,0 +1,99 @@
+use clap::Args;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file, or from stdin when `-`
+    #[arg(long, short, conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+    /// Use the day's `example.txt` instead of its `input.txt`
+    #[arg(long)]
+    pub example: bool,
+}
+
+impl InputArgs {
+    /// An explicit `--input` is shared by every selected day, so it only makes
+    /// sense when a single day runs.
+    pub fn check_days(&self, count: usize) -> Result<(), &'static str> {
+        if self.input.is_some() && count > 1 {
+            return Err("--input can only be used with a single day");
+        }
+        Ok(())
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match &self.input {
+            Some(path) if path == Path::new("-") => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| InputError::new("<stdin>", err))?;
+                Ok(input)
+            }
+            Some(path) => read_file(path),
+            None => read_file(&default_path(day, self.example)),
+        }
+    }
+
+    /// Opens the input to be read a line at a time instead of all at once.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        match &self.input {
+            Some(path) if path == Path::new("-") => Ok(Box::new(io::stdin().lock())),
+            Some(path) => open_file(path),
+            None => open_file(&default_path(day, self.example)),
+        }
+    }
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day))
+}
+
+pub fn default_path(day: u8, example: bool) -> PathBuf {
+    let file = if example { "example.txt" } else { "input.txt" };
+    day_dir(day).join(file)
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|err| InputError::new(path.display(), err))
+}
+
+fn open_file(path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    let file = File::open(path).map_err(|err| InputError::new(path.display(), err))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    path: String,
+    source: io::Error,
+}
+
+impl InputError {
+    fn new(path: impl fmt::Display, source: io::Error) -> Self {
+        InputError {
+            path: path.to_string(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot read `{}`: {}", self.path, self.source)?;
+        if self.source.kind() == io::ErrorKind::NotFound {
+            write!(f, " (run from the repository root or pass --input)")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
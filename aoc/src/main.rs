@@ -0,0 +1,303 @@
+mod bench;
+mod days;
+mod diff;
+mod fetch;
+mod generate;
+mod input;
+mod memory;
+mod report;
+mod scaffold;
+mod verify;
+
+use bench::BenchArgs;
+use clap::{Args, Parser, Subcommand};
+use days::{Day, DayRun};
+use diff::DiffArgs;
+use fetch::FetchArgs;
+use generate::GenArgs;
+use input::InputArgs;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use report::Format;
+use std::error::Error;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, a range of days or all of them
+    Run(RunArgs),
+    /// Check every day's answers against its `answers.toml`
+    Verify(VerifyArgs),
+    /// Time parsing and both parts over many runs and compare with a baseline
+    Bench(BenchCommand),
+    /// Download puzzle inputs into the day directories
+    Fetch(FetchCommand),
+    /// Generate the crate for a new day and register it with the runner
+    New(NewArgs),
+    /// Write random puzzle inputs, for stress tests and benchmarks at scale
+    Gen(GenCommand),
+    /// Compare days with their slow reference solvers on random inputs
+    Diff(DiffCommand),
+    /// Print, for every line of the input, why it fails the puzzle's rules
+    Explain(ExplainCommand),
+}
+
+#[derive(Args)]
+struct DaySelection {
+    /// Day to run, either a single day (`7`) or an inclusive range (`3-7`)
+    #[arg(long, value_parser = parse_days, conflicts_with = "all")]
+    day: Option<RangeInclusive<u8>>,
+    /// Run every registered day
+    #[arg(long)]
+    all: bool,
+}
+
+impl DaySelection {
+    /// Resolves the selected days, falling back to every day when nothing was
+    /// passed and `default_all` is set.
+    fn resolve(&self, default_all: bool) -> Result<Vec<&'static Day>, Box<dyn Error>> {
+        let selected: Vec<&Day> = if let Some(range) = &self.day {
+            range.clone().filter_map(days::find).collect()
+        } else if self.all || default_all {
+            days::DAYS.iter().collect()
+        } else {
+            return Err("pass either --day or --all".into());
+        };
+        if selected.is_empty() {
+            return Err("no registered day in the selected range".into());
+        }
+        Ok(selected)
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    days: DaySelection,
+    /// Only run the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    #[command(flatten)]
+    input: InputArgs,
+    /// Output format for answers and timings
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Read the input a line at a time and sort it through temporary files,
+    /// for inputs larger than memory; only day 1 supports it
+    #[arg(long)]
+    stream: bool,
+    /// Number of days solved at the same time
+    #[arg(long, short, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    days: DaySelection,
+}
+
+#[derive(Args)]
+struct BenchCommand {
+    #[command(flatten)]
+    days: DaySelection,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    bench: BenchArgs,
+}
+
+#[derive(Args)]
+struct FetchCommand {
+    #[command(flatten)]
+    days: DaySelection,
+    #[command(flatten)]
+    fetch: FetchArgs,
+}
+
+#[derive(Args)]
+struct GenCommand {
+    #[command(flatten)]
+    days: DaySelection,
+    #[command(flatten)]
+    gen: GenArgs,
+}
+
+#[derive(Args)]
+struct DiffCommand {
+    #[command(flatten)]
+    days: DaySelection,
+    #[command(flatten)]
+    diff: DiffArgs,
+}
+
+#[derive(Args)]
+struct ExplainCommand {
+    #[command(flatten)]
+    days: DaySelection,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse = |s: &str| {
+        s.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("`{}` is not a day number", s))
+    };
+    let range = match value.split_once('-') {
+        Some((start, end)) => parse(start)?..=parse(end)?,
+        None => {
+            let day = parse(value)?;
+            day..=day
+        }
+    };
+    if range.is_empty() {
+        return Err(format!("`{}` is an empty range", value));
+    }
+    Ok(range)
+}
+
+/// Errors that can cross from a worker thread back to `main`.
+type SendError = Box<dyn Error + Send + Sync>;
+
+fn solve(day: &Day, args: &RunArgs) -> Result<DayRun, SendError> {
+    if let Some(stream) = days::stream(day.number).filter(|_| args.stream) {
+        return stream(&mut args.input.open(day.number)?, args.part);
+    }
+    let input = args.input.read(day.number)?;
+    (day.run)(&input, args.part)
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    let selected = args.days.resolve(false)?;
+    args.input.check_days(selected.len())?;
+    if args.stream {
+        if let Some(day) = selected
+            .iter()
+            .find(|day| days::stream(day.number).is_none())
+        {
+            return Err(format!("day {:02} cannot be streamed", day.number).into());
+        }
+    }
+    let start = Instant::now();
+    let results = if args.jobs > 1 {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(args.jobs as usize)
+            .build()?;
+        // every day times itself, and collecting keeps the selection order
+        // whichever day finishes first
+        pool.install(|| {
+            selected
+                .par_iter()
+                .map(|day| Ok((day.number, solve(day, &args)?)))
+                .collect::<Result<Vec<_>, SendError>>()
+        })
+        .map_err(|err| err as Box<dyn Error>)?
+    } else {
+        let mut results = Vec::new();
+        for day in &selected {
+            let run = solve(day, &args).map_err(|err| err as Box<dyn Error>)?;
+            if args.format == Format::Text {
+                print!("{}", report::text(day.number, &run));
+            }
+            results.push((day.number, run));
+        }
+        results
+    };
+    let total = start.elapsed();
+
+    if args.format != Format::Text {
+        print!("{}", report::render(args.format, &results));
+        return Ok(());
+    }
+    if args.jobs > 1 {
+        print!("{}", report::render(Format::Text, &results));
+    }
+    if results.len() > 1 {
+        println!("\nTotal took {:#?}", total);
+    }
+    Ok(())
+}
+
+fn explain(args: ExplainCommand) -> Result<(), Box<dyn Error>> {
+    let selected = args.days.resolve(false)?;
+    args.input.check_days(selected.len())?;
+    let explains = selected
+        .iter()
+        .map(|day| {
+            days::explain(day.number)
+                .map(|explain| (day.number, explain))
+                .ok_or_else(|| format!("day {:02} cannot be explained", day.number))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for (number, explain) in explains {
+        let input = args.input.read(number)?;
+        print!("{}", explain(&input)?);
+    }
+    Ok(())
+}
+
+fn new_day(args: NewArgs) -> Result<(), Box<dyn Error>> {
+    for path in scaffold::new_day(Path::new("."), args.day)? {
+        println!("wrote `{}`", path.display());
+    }
+    println!(
+        "\nnext: paste the example into example.txt, then `aoc fetch --day {}`",
+        args.day
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => args
+            .days
+            .resolve(true)
+            .and_then(|days| verify::verify(&days)),
+        Command::Bench(args) => args.days.resolve(true).and_then(|days| {
+            args.input.check_days(days.len())?;
+            bench::bench(&days, &args.input, &args.bench)
+        }),
+        Command::Fetch(args) => args.days.resolve(false).and_then(|days| {
+            let numbers: Vec<u8> = days.iter().map(|day| day.number).collect();
+            fetch::fetch(&numbers, &args.fetch)
+        }),
+        Command::New(args) => new_day(args),
+        Command::Gen(args) => args.days.resolve(false).and_then(|days| {
+            let numbers: Vec<u8> = days.iter().map(|day| day.number).collect();
+            generate::generate(&numbers, &args.gen)
+        }),
+        Command::Diff(args) => args.days.resolve(true).and_then(|days| {
+            let numbers: Vec<u8> = days.iter().map(|day| day.number).collect();
+            diff::diff(&numbers, &args.diff)
+        }),
+        Command::Explain(args) => explain(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use serde::Serialize;
+
+/// What one phase allocated, counted by the global allocator when the
+/// `alloc-stats` feature is on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Allocations {
+    /// Calls to `alloc` and `realloc`
+    pub count: u64,
+    /// Bytes asked for by those calls, a `realloc` counting its new size
+    pub bytes: u64,
+    /// Highest amount of memory in use at once, above what was already in
+    /// use when the phase started
+    pub peak: u64,
+}
+
+/// Runs `f` and reports what it allocated, or `None` when allocations are
+/// not counted in this build.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        counting::measure(f)
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::Allocations;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[derive(Clone, Copy)]
+    struct Counters {
+        count: u64,
+        bytes: u64,
+        // memory freed here may have been allocated by another thread, so
+        // this can go below zero
+        live: i64,
+        peak: i64,
+    }
+
+    impl Counters {
+        fn allocated(&mut self, size: usize) {
+            self.count += 1;
+            self.bytes += size as u64;
+            self.grow(size as i64);
+        }
+
+        fn grow(&mut self, by: i64) {
+            self.live += by;
+            self.peak = self.peak.max(self.live);
+        }
+    }
+
+    // counted per thread, so that days solved side by side with `--jobs` do
+    // not add up; a plain `Cell` never allocates itself
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                count: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn record(update: impl FnOnce(&mut Counters)) {
+        // the thread-local is gone while the thread shuts down
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            update(&mut counters);
+            cell.set(counters);
+        });
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(|counters| counters.allocated(layout.size()));
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(|counters| counters.allocated(layout.size()));
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(|counters| counters.grow(-(layout.size() as i64)));
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                record(|counters| {
+                    counters.allocated(new_size);
+                    counters.grow(-(layout.size() as i64));
+                });
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+        let mut start = COUNTERS.with(Cell::get);
+        start.peak = start.live;
+        COUNTERS.with(|cell| cell.set(start));
+        let value = f();
+        let end = COUNTERS.with(Cell::get);
+        let allocations = Allocations {
+            count: end.count - start.count,
+            bytes: end.bytes - start.bytes,
+            peak: (end.peak - start.live).max(0) as u64,
+        };
+        (value, Some(allocations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn counts_what_the_closure_allocates() {
+        let (_, allocations) = measure(|| {
+            let small = vec![1u8; 100];
+            let big = vec![2u8; 10_000];
+            drop(big);
+            small
+        });
+        let allocations = allocations.unwrap();
+        assert_eq!(allocations.count, 2);
+        assert_eq!(allocations.bytes, 10_100);
+        assert_eq!(allocations.peak, 10_100);
+
+        let (_, allocations) = measure(|| 1 + 1);
+        assert_eq!(allocations, Some(Allocations::default()));
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc-stats"))]
+    fn nothing_is_counted_without_the_feature() {
+        assert_eq!(measure(|| vec![1u8; 100]).1, None);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::days::DayRun;
+use crate::memory::Allocations;
+use clap::ValueEnum;
+use common::Answer;
+use serde::{Serialize, Serializer};
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable lines, printed as each day finishes
+    Text,
+    Json,
+    Csv,
+    /// A results table ready to paste into the README
+    Markdown,
+}
+
+/// One solved part, flattened for machine-readable output.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    #[serde(serialize_with = "serialize_answer")]
+    answer: &'a Answer,
+    parse_ns: u64,
+    solve_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_allocations: Option<Allocations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solve_allocations: Option<Allocations>,
+}
+
+fn serialize_answer<S: Serializer>(answer: &&Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Signed(value) => serializer.serialize_i64(*value),
+        Answer::Unsigned(value) => serializer.serialize_u64(*value),
+    }
+}
+
+fn records(results: &[(u8, DayRun)]) -> Vec<Record<'_>> {
+    results
+        .iter()
+        .flat_map(|(day, run)| {
+            run.parts.iter().map(move |part| Record {
+                day: *day,
+                part: part.part,
+                answer: &part.answer,
+                parse_ns: run.parse.as_nanos() as u64,
+                solve_ns: part.duration.as_nanos() as u64,
+                parse_allocations: run.parse_allocations,
+                solve_allocations: part.allocations,
+            })
+        })
+        .collect()
+}
+
+/// Sizes in bytes, with binary prefixes past a KiB.
+fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// ` (N allocations, X, peak Y)` when allocations were counted.
+fn allocations(allocations: &Option<Allocations>) -> String {
+    allocations.map_or(String::new(), |a| {
+        format!(
+            " ({} allocations, {}, peak {})",
+            a.count,
+            bytes(a.bytes),
+            bytes(a.peak)
+        )
+    })
+}
+
+pub fn text(day: u8, run: &DayRun) -> String {
+    let mut out = format!(
+        "Day {:02}\nParse took {:#?}{}\n",
+        day,
+        run.parse,
+        allocations(&run.parse_allocations)
+    );
+    for part in &run.parts {
+        writeln!(
+            out,
+            "Part {}: {} took {:#?}{}",
+            part.part,
+            part.answer,
+            part.duration,
+            allocations(&part.allocations)
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn csv(results: &[(u8, DayRun)]) -> String {
+    let records = records(results);
+    // every run of a build counts allocations, or none does
+    let counted = records.iter().any(|r| r.solve_allocations.is_some());
+    let mut out = String::from("day,part,answer,parse_ns,solve_ns");
+    if counted {
+        out.push_str(",parse_allocs,parse_bytes,parse_peak,solve_allocs,solve_bytes,solve_peak");
+    }
+    out.push('\n');
+    for r in records {
+        write!(
+            out,
+            "{},{},{},{},{}",
+            r.day, r.part, r.answer, r.parse_ns, r.solve_ns
+        )
+        .unwrap();
+        for a in [r.parse_allocations, r.solve_allocations]
+            .into_iter()
+            .flatten()
+        {
+            write!(out, ",{},{},{}", a.count, a.bytes, a.peak).unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn markdown(results: &[(u8, DayRun)]) -> String {
+    let mut out = String::from(
+        "| Day | Part 1 | Part 2 | Parse | Part 1 time | Part 2 time |\n\
+         |----:|-------:|-------:|------:|------------:|------------:|\n",
+    );
+    for (day, run) in results {
+        let part = |number| run.parts.iter().find(|p| p.part == number);
+        let answer = |number| part(number).map_or("-".to_string(), |p| p.answer.to_string());
+        let time = |number| part(number).map_or("-".to_string(), |p| duration(p.duration));
+        writeln!(
+            out,
+            "| [{:02}](day{:02}/src/lib.rs) | {} | {} | {} | {} | {} |",
+            day,
+            day,
+            answer(1),
+            answer(2),
+            duration(run.parse),
+            time(1),
+            time(2)
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// Renders all results at once; text output is printed per day instead.
+pub fn render(format: Format, results: &[(u8, DayRun)]) -> String {
+    match format {
+        Format::Text => results.iter().map(|(day, run)| text(*day, run)).collect(),
+        Format::Json => serde_json::to_string_pretty(&records(results)).unwrap() + "\n",
+        Format::Csv => csv(results),
+        Format::Markdown => markdown(results),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::PartRun;
+
+    fn results() -> Vec<(u8, DayRun)> {
+        let run = DayRun {
+            parse: Duration::from_nanos(1500),
+            parse_allocations: None,
+            parts: vec![
+                PartRun {
+                    part: 1,
+                    answer: Answer::Signed(-7),
+                    duration: Duration::from_nanos(200),
+                    allocations: None,
+                },
+                PartRun {
+                    part: 2,
+                    answer: Answer::Unsigned(42),
+                    duration: Duration::from_micros(3),
+                    allocations: None,
+                },
+            ],
+        };
+        vec![(3, run)]
+    }
+
+    #[test]
+    fn csv_has_one_row_per_part() {
+        assert_eq!(
+            render(Format::Csv, &results()),
+            "day,part,answer,parse_ns,solve_ns\n3,1,-7,1500,200\n3,2,42,1500,3000\n"
+        );
+    }
+
+    #[test]
+    fn json_keeps_answers_numeric() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &results())).unwrap();
+        assert_eq!(json[0]["answer"], -7);
+        assert_eq!(json[1]["answer"], 42);
+        assert_eq!(json[1]["solve_ns"], 3000);
+    }
+
+    fn counted(results: &mut [(u8, DayRun)]) {
+        let (_, run) = &mut results[0];
+        run.parse_allocations = Some(Allocations {
+            count: 3,
+            bytes: 2048,
+            peak: 1000,
+        });
+        for part in &mut run.parts {
+            part.allocations = Some(Allocations::default());
+        }
+    }
+
+    #[test]
+    fn allocations_are_only_shown_when_counted() {
+        let mut results = results();
+        assert!(!render(Format::Text, &results).contains("allocations"));
+        assert!(!render(Format::Json, &results).contains("allocations"));
+
+        counted(&mut results);
+        let text = render(Format::Text, &results);
+        assert!(text.contains("Parse took 1.5µs (3 allocations, 2.0 KiB, peak 1000 B)\n"));
+        assert!(text.contains("Part 1: -7 took 200ns (0 allocations, 0 B, peak 0 B)\n"));
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &results)).unwrap();
+        assert_eq!(json[0]["parse_allocations"]["bytes"], 2048);
+        assert_eq!(json[1]["solve_allocations"]["count"], 0);
+        assert_eq!(
+            render(Format::Csv, &results).lines().nth(1),
+            Some("3,1,-7,1500,200,3,2048,1000,0,0,0")
+        );
+    }
+
+    #[test]
+    fn bytes_use_binary_prefixes() {
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn markdown_has_one_row_per_day() {
+        let table = render(Format::Markdown, &results());
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[2],
+            "| [03](day03/src/lib.rs) | -7 | 42 | 1.50µs | 200.00ns | 3.00µs |"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use crate::input;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.in");
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{N}}", &day.to_string())
+}
+
+/// Reads the day number out of a `dayNN` name at the start of `text`.
+fn day_prefix(text: &str) -> Option<u8> {
+    let digits = text.strip_prefix("day")?.get(..2)?;
+    digits.parse().ok()
+}
+
+/// Inserts `new_line` among the lines for which `day_of` finds a day, keeping
+/// them sorted and copying the indentation of a neighbour.
+fn register(
+    path: &Path,
+    text: &str,
+    day: u8,
+    new_line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, Box<dyn Error>> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line.trim()).map(|d| (i, d)))
+        .collect();
+    if registered.iter().any(|&(_, d)| d == day) {
+        return Err(format!(
+            "day {:02} is already registered in `{}`",
+            day,
+            path.display()
+        )
+        .into());
+    }
+    let Some(&(first, _)) = registered.first() else {
+        return Err(format!("cannot find where to register days in `{}`", path.display()).into());
+    };
+    let (at, neighbour) = match registered.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => (i + 1, i),
+        None => (first, first),
+    };
+    let indent = &lines[neighbour][..lines[neighbour].len() - lines[neighbour].trim_start().len()];
+    let line = format!("{}{}", indent, new_line);
+    lines.insert(at, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the new day to `DAYS` and bumps the array length.
+fn register_in_days(path: &Path, text: &str, day: u8) -> Result<String, Box<dyn Error>> {
+    let entry = format!("day::<day{:02}::Day{:02}>(),", day, day);
+    let text = register(path, text, day, &entry, |line| {
+        line.strip_prefix("day::<").and_then(day_prefix)
+    })?;
+
+    let declaration = "pub const DAYS: [Day; ";
+    let start = text
+        .find(declaration)
+        .ok_or_else(|| format!("cannot find `DAYS` in `{}`", path.display()))?
+        + declaration.len();
+    let end = start
+        + text[start..]
+            .find(']')
+            .ok_or_else(|| format!("cannot read the length of `DAYS` in `{}`", path.display()))?;
+    let count: usize = text[start..end].parse()?;
+    Ok(format!("{}{}{}", &text[..start], count + 1, &text[end..]))
+}
+
+/// A file to create or replace once every check passed.
+struct Write {
+    path: PathBuf,
+    contents: String,
+}
+
+fn read(path: &Path) -> Result<String, Box<dyn Error>> {
+    fs::read_to_string(path)
+        .map_err(|err| format!("cannot read `{}`: {}", path.display(), err).into())
+}
+
+/// Creates the `dayNN` crate under `root` and registers it with the workspace
+/// and the runner. Nothing is written unless every step can be done.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let dir = root.join(input::day_dir(day));
+    if dir.exists() {
+        return Err(format!("`{}` already exists", dir.display()).into());
+    }
+
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let days = root.join("aoc").join("src").join("days.rs");
+    let name = format!("day{:02}", day);
+
+    let writes = [
+        Write {
+            contents: register(
+                &workspace,
+                &read(&workspace)?,
+                day,
+                &format!("\"{}\",", name),
+                |line| line.strip_prefix('"').and_then(day_prefix),
+            )?,
+            path: workspace,
+        },
+        Write {
+            contents: register(
+                &runner,
+                &read(&runner)?,
+                day,
+                &format!("{} = {{ path = \"../{}\" }}", name, name),
+                day_prefix,
+            )?,
+            path: runner,
+        },
+        Write {
+            contents: register_in_days(&days, &read(&days)?, day)?,
+            path: days,
+        },
+        Write {
+            path: dir.join("Cargo.toml"),
+            contents: render(CARGO_TEMPLATE, day),
+        },
+        Write {
+            path: dir.join("src").join("lib.rs"),
+            contents: render(LIB_TEMPLATE, day),
+        },
+        Write {
+            path: dir.join("example.txt"),
+            contents: String::new(),
+        },
+    ];
+
+    fs::create_dir_all(dir.join("src"))?;
+    for write in &writes {
+        fs::write(&write.path, &write.contents)
+            .map_err(|err| format!("cannot write `{}`: {}", write.path.display(), err))?;
+    }
+    Ok(writes.into_iter().map(|write| write.path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A copy of the registration files of this repository.
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::write(
+            root.path().join("Cargo.toml"),
+            include_str!("../../Cargo.toml"),
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("aoc/Cargo.toml"),
+            include_str!("../Cargo.toml"),
+        )
+        .unwrap();
+        fs::write(root.path().join("aoc/src/days.rs"), include_str!("days.rs")).unwrap();
+        root
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn creates_and_registers_a_day() {
+        let root = workspace();
+        let root = root.path();
+        let count = crate::days::DAYS.len();
+        let next = count as u8 + 1;
+        let name = format!("day{:02}", next);
+
+        new_day(root, next).unwrap();
+
+        let lib = read(root, &format!("{}/src/lib.rs", name));
+        assert!(lib.contains(&format!("pub struct Day{:02};", next)));
+        assert!(lib.contains(&format!("const DAY: u8 = {};", next)));
+        assert!(!lib.contains("{{"));
+        assert!(read(root, &format!("{}/Cargo.toml", name)).contains(&format!("\"{}\"", name)));
+        assert_eq!(read(root, &format!("{}/example.txt", name)), "");
+
+        assert!(read(root, "Cargo.toml").contains(&format!("    \"{}\",\n", name)));
+        assert!(read(root, "aoc/Cargo.toml")
+            .contains(&format!("{} = {{ path = \"../{}\" }}\n", name, name)));
+        let days = read(root, "aoc/src/days.rs");
+        assert!(days.contains(&format!("[Day; {}]", count + 1)));
+        assert!(days.contains(&format!("    day::<day{:02}::Day{:02}>(),\n];", next, next)));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_a_day() {
+        let root = workspace();
+        let root = root.path();
+        fs::create_dir_all(root.join("day03/src")).unwrap();
+        fs::write(root.join("day03/src/lib.rs"), "// solved").unwrap();
+        let before = read(root, "aoc/src/days.rs");
+
+        let err = new_day(root, 3).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert_eq!(read(root, "day03/src/lib.rs"), "// solved");
+        assert_eq!(read(root, "aoc/src/days.rs"), before);
+    }
+
+    #[test]
+    fn refuses_a_day_registered_without_a_directory() {
+        let root = workspace();
+        let root = root.path();
+        let before = read(root, "Cargo.toml");
+
+        let err = new_day(root, 3).unwrap_err();
+        assert!(err.to_string().contains("already registered"));
+        assert!(!root.join("day03").exists());
+        assert_eq!(read(root, "Cargo.toml"), before);
+    }
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let text = "[list]\n  \"day01\",\n  \"day07\",\n  \"other\",\n";
+        let day_of = |line: &str| line.strip_prefix('"').and_then(day_prefix);
+        let path = Path::new("list");
+        assert_eq!(
+            register(path, text, 4, "\"day04\",", day_of).unwrap(),
+            "[list]\n  \"day01\",\n  \"day04\",\n  \"day07\",\n  \"other\",\n"
+        );
+        assert_eq!(
+            register(path, text, 0, "\"day00\",", day_of).unwrap(),
+            "[list]\n  \"day00\",\n  \"day01\",\n  \"day07\",\n  \"other\",\n"
+        );
+        assert!(register(path, "[list]\n", 4, "\"day04\",", day_of).is_err());
+    }
+}
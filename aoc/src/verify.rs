@@ -0,0 +1,163 @@
+use crate::days::Day;
+use crate::input;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+
+/// Expected answers for one day, read from `dayNN/answers.toml`.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct Answers {
+    input: Option<Expected>,
+    example: Option<Expected>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<ExpectedAnswer>,
+    part2: Option<ExpectedAnswer>,
+}
+
+/// Answers are usually plain integers, but a string is accepted for anything
+/// that does not fit in a TOML integer.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExpectedAnswer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExpectedAnswer::Number(value) => write!(f, "{}", value),
+            ExpectedAnswer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+struct Row {
+    day: u8,
+    source: &'static str,
+    part: u8,
+    expected: String,
+    actual: String,
+    status: Status,
+}
+
+fn load_answers(day: u8) -> Result<Answers, Box<dyn Error>> {
+    let path = input::day_dir(day).join("answers.toml");
+    match fs::read_to_string(&path) {
+        Ok(text) => toml::from_str(&text)
+            .map_err(|err| format!("invalid `{}`: {}", path.display(), err).into()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(err) => Err(format!("cannot read `{}`: {}", path.display(), err).into()),
+    }
+}
+
+fn verify_source(day: &Day, source: &'static str, expected: Option<Expected>) -> Vec<Row> {
+    let expected = expected.unwrap_or_default();
+    let expected = [(1, expected.part1), (2, expected.part2)];
+    let path = input::default_path(day.number, source == "example");
+    let text = fs::read_to_string(&path);
+    let actual: Result<Vec<String>, String> = match &text {
+        Ok(text) => (day.run)(text, None)
+            .map(|run| run.parts.iter().map(|p| p.answer.to_string()).collect())
+            .map_err(|err| format!("error: {}", err)),
+        Err(_) => Err(format!("no {}", path.display())),
+    };
+
+    expected
+        .into_iter()
+        .map(|(part, expected)| {
+            let actual = match &actual {
+                Ok(answers) => answers[part as usize - 1].clone(),
+                Err(err) => err.clone(),
+            };
+            let status = match &expected {
+                None => Status::Missing,
+                Some(_) if text.is_err() => Status::Missing,
+                Some(expected) if expected.to_string() == actual => Status::Pass,
+                Some(_) => Status::Fail,
+            };
+            Row {
+                day: day.number,
+                source,
+                part,
+                expected: expected.map_or_else(|| "-".to_string(), |e| e.to_string()),
+                actual,
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Runs every selected day against its recorded answers, prints a table and
+/// fails if any answer does not match.
+pub fn verify(selected: &[&Day]) -> Result<(), Box<dyn Error>> {
+    let mut rows = Vec::new();
+    for day in selected {
+        let answers = load_answers(day.number)?;
+        rows.extend(verify_source(day, "input", answers.input));
+        rows.extend(verify_source(day, "example", answers.example));
+    }
+
+    let expected_width = rows.iter().map(|r| r.expected.len()).max().unwrap_or(0);
+    let actual_width = rows.iter().map(|r| r.actual.len()).max().unwrap_or(0);
+    println!(
+        "{:<4} {:<8} {:<5} {:<ew$} {:<aw$} Status",
+        "Day",
+        "Input",
+        "Part",
+        "Expected",
+        "Actual",
+        ew = expected_width.max(8),
+        aw = actual_width.max(6),
+    );
+    for row in &rows {
+        println!(
+            "{:<4} {:<8} {:<5} {:<ew$} {:<aw$} {}",
+            format!("{:02}", row.day),
+            row.source,
+            row.part,
+            row.expected,
+            row.actual,
+            row.status,
+            ew = expected_width.max(8),
+            aw = actual_width.max(6),
+        );
+    }
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    let failed = count(Status::Fail);
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        failed,
+        count(Status::Missing)
+    );
+    if failed > 0 {
+        return Err(format!("{} answer(s) did not match", failed).into());
+    }
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// A point or offset on the plane; `x` grows to the right and `y` downwards,
+/// so on a [`Grid`](crate::Grid) `x` is the column and `y` the row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Converts both coordinates, e.g. `p.map(|v| v as isize)`.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Point<U> {
+        Point::new(f(self.x), f(self.y))
+    }
+
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+    {
+        // written with max - min so it also works for unsigned coordinates
+        let dx = self.x.max(other.x) - self.x.min(other.x);
+        let dy = self.y.max(other.y) - self.y.min(other.y);
+        dx + dy
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+macro_rules! impl_wrapping {
+    ($($t:ty),+) => {
+        $(
+            impl Point<$t> {
+                /// Wraps the point onto a torus of the given size, so that
+                /// every coordinate ends up in `0..size`.
+                pub fn wrap(self, size: Self) -> Self {
+                    Point::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+                }
+
+                /// Moves `steps` times by `delta` on a torus of the given size.
+                pub fn wrapping_step(self, delta: Self, steps: $t, size: Self) -> Self {
+                    (self + delta * steps).wrap(size)
+                }
+            }
+        )+
+    };
+}
+
+impl_wrapping!(i32, i64, isize);
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Right,
+            Direction4::Right => Direction4::Down,
+            Direction4::Down => Direction4::Left,
+            Direction4::Left => Direction4::Up,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The offset of one step in this direction, with `y` growing downwards.
+    pub const fn delta(self) -> Point<isize> {
+        match self {
+            Direction4::Up => Point::new(0, -1),
+            Direction4::Right => Point::new(1, 0),
+            Direction4::Down => Point::new(0, 1),
+            Direction4::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// One of the eight compass directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from `North`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns clockwise by 45 degrees.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction8::North => Direction8::NorthEast,
+            Direction8::NorthEast => Direction8::East,
+            Direction8::East => Direction8::SouthEast,
+            Direction8::SouthEast => Direction8::South,
+            Direction8::South => Direction8::SouthWest,
+            Direction8::SouthWest => Direction8::West,
+            Direction8::West => Direction8::NorthWest,
+            Direction8::NorthWest => Direction8::North,
+        }
+    }
+
+    /// Turns counter-clockwise by 45 degrees.
+    pub const fn turn_left(self) -> Self {
+        self.opposite().turn_right().turn_right().turn_right()
+    }
+
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right().turn_right().turn_right()
+    }
+
+    /// The offset of one step in this direction, with `y` growing downwards.
+    pub const fn delta(self) -> Point<isize> {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Direction8::North,
+            Direction4::Right => Direction8::East,
+            Direction4::Down => Direction8::South,
+            Direction4::Left => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(1, 5);
+        assert_eq!(a + b, Point::new(4, 3));
+        assert_eq!(a - b, Point::new(2, -7));
+        assert_eq!(a * 3, Point::new(9, -6));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn manhattan_works_for_unsigned_coordinates() {
+        assert_eq!(Point::new(3, -2).manhattan(Point::new(1, 5)), 9);
+        assert_eq!(Point::new(1usize, 7).manhattan(Point::new(4, 2)), 8);
+    }
+
+    #[test]
+    fn wrap_keeps_coordinates_in_range() {
+        let size = Point::new(11, 7);
+        assert_eq!(Point::new(-1i32, 7).wrap(size), Point::new(10, 0));
+        assert_eq!(Point::new(25i32, -15).wrap(size), Point::new(3, 6));
+        // the robot from the day 14 example, after five seconds
+        let start = Point::new(2i64, 4);
+        assert_eq!(
+            start.wrapping_step(Point::new(2, -3), 5, Point::new(11, 7)),
+            Point::new(1, 3)
+        );
+    }
+
+    #[test]
+    fn direction4_turns() {
+        for dir in Direction4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::new(0, 0));
+        }
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+    }
+
+    #[test]
+    fn direction8_turns() {
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::new(0, 0));
+        }
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::from(Direction4::Left), Direction8::West);
+    }
+}
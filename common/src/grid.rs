@@ -0,0 +1,248 @@
+use crate::geometry::{Direction4, Direction8, Point};
+use crate::{ParseError, Source};
+use std::ops::{Index, IndexMut};
+
+/// A cell of a [`Grid`]: `x` is the column and `y` the row.
+pub type Position = Point<usize>;
+
+/// A rectangular grid stored row by row in a single vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one cell per character, rejecting empty input, ragged rows and
+    /// characters for which `cell` returns `None`.
+    pub fn parse(
+        source: Source,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let first = source.lines().next().filter(|line| !line.is_empty());
+        let first = first.ok_or_else(|| source.error(&source.text()[..0], "a grid row"))?;
+        let width = first.chars().count();
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in source.lines() {
+            let mut count = 0;
+            for (i, c) in line.char_indices() {
+                if count == width {
+                    return Err(source.error(&line[i..], format!("a row of {} cells", width)));
+                }
+                let value =
+                    cell(c).ok_or_else(|| source.error(&line[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+                count += 1;
+            }
+            if count < width {
+                return Err(source.error_after(line, format!("a row of {} cells", width)));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// Converts a signed point to a position, if it falls inside the grid.
+    pub fn position(&self, point: Point<isize>) -> Option<Position> {
+        let pos = Point::new(
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The position one step of `delta` away from `pos`.
+    pub fn offset(&self, pos: Position, delta: Point<isize>) -> Option<Position> {
+        self.position(pos.map(|v| v as isize) + delta)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(col, row)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction4::ALL
+            .iter()
+            .filter_map(move |dir| self.offset(pos, dir.delta()))
+    }
+
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .iter()
+            .filter_map(move |dir| self.offset(pos, dir.delta()))
+    }
+
+    /// Positions from `start` (included) stepping by `delta` until the edge
+    /// of the grid, covering rows, columns and diagonals alike.
+    pub fn ray(&self, start: Position, delta: Point<isize>) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.contains(start).then_some(start), move |&pos| {
+            self.offset(pos, delta)
+        })
+    }
+
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |&(_, cell)| cell == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl Grid<char> {
+    /// Parses any rectangular block of characters.
+    pub fn parse_chars(source: Source) -> Result<Self, ParseError> {
+        Grid::parse(source, "a character", Some)
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a block of decimal digits, one cell per digit.
+    pub fn parse_digits(source: Source) -> Result<Self, ParseError> {
+        Grid::parse(source, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos).expect("position outside of the grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse_chars(Source::new(0, text)).unwrap()
+    }
+
+    fn at(x: usize, y: usize) -> Position {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn parse_stores_rows_in_order() {
+        let g = grid("abc\ndef");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[at(0, 1)], 'd');
+        assert_eq!(g.get(at(3, 0)), None);
+        assert_eq!(g.get(at(0, 2)), None);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows_and_bad_cells() {
+        let err = Grid::parse_chars(Source::new(4, "abc\nab\nabc")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = Grid::parse_chars(Source::new(4, "abc\nabcd")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, "`d`"));
+        assert!(Grid::parse_chars(Source::new(4, "")).is_err());
+        let err = Grid::parse_digits(Source::new(10, "12\n3x")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 2, "a digit")
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let g = grid("abc\ndef\nghi");
+        assert_eq!(g.neighbours4(at(0, 0)).count(), 2);
+        assert_eq!(g.neighbours4(at(1, 1)).count(), 4);
+        assert_eq!(g.neighbours8(at(0, 0)).count(), 3);
+        assert_eq!(g.neighbours8(at(1, 1)).count(), 8);
+        assert_eq!(g.neighbours8(at(1, 2)).count(), 5);
+    }
+
+    #[test]
+    fn rays_run_to_the_edge() {
+        let g = grid("abc\ndef\nghi");
+        let cells =
+            |start, dir: Direction8| g.ray(start, dir.delta()).map(|p| g[p]).collect::<String>();
+        assert_eq!(cells(at(0, 0), Direction8::East), "abc");
+        assert_eq!(cells(at(1, 2), Direction8::North), "heb");
+        assert_eq!(cells(at(0, 0), Direction8::SouthEast), "aei");
+        assert_eq!(cells(at(0, 2), Direction8::NorthEast), "gec");
+        assert_eq!(g.ray(at(0, 3), Point::new(1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn find_and_positions_of() {
+        let g = grid("a.a\n.a.");
+        assert_eq!(g.find(&'.'), Some(at(1, 0)));
+        assert_eq!(g.find(&'z'), None);
+        let found: Vec<Position> = g.positions_of(&'a').collect();
+        assert_eq!(found, vec![at(0, 0), at(2, 0), at(1, 1)]);
+    }
+
+    #[test]
+    fn position_rejects_negative_and_large_coordinates() {
+        let g = Grid::new(2, 3, 0);
+        assert_eq!(g.position(Point::new(1, 2)), Some(at(1, 2)));
+        assert_eq!(g.position(Point::new(0, -1)), None);
+        assert_eq!(g.position(Point::new(2, 0)), None);
+        assert_eq!(g.offset(at(0, 0), Direction4::Up.delta()), None);
+    }
+}
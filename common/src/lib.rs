@@ -0,0 +1,101 @@
+pub mod geometry;
+pub mod grid;
+mod parse;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub use geometry::{Direction4, Direction8, Point};
+pub use grid::Grid;
+pub use parse::{ParseError, Source};
+
+use std::error::Error;
+use std::fmt;
+
+/// A puzzle answer, widened so every day can report through the same type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $wide:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Signed, i64, i32, i64);
+impl_from!(Unsigned, u64, u32, u64, usize);
+
+/// Why a part has no answer for an input that parsed, such as an answer
+/// too large for its type.
+pub type SolveError = Box<dyn Error + Send + Sync>;
+
+/// An answer, or a value computed on the way to it, that does not fit in the
+/// type it is computed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u8,
+    pub part: u8,
+    pub width: &'static str,
+}
+
+impl Overflow {
+    pub fn of<W>(day: u8, part: u8) -> Self {
+        Overflow {
+            day,
+            part,
+            width: std::any::type_name::<W>(),
+        }
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {:02} part {} does not fit in {}",
+            self.day, self.part, self.width
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// Common interface implemented by every day.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+}
+
+/// A slow solver that follows the puzzle text step by step, to check the
+/// shortcuts of a day's `Solution` against.
+pub trait Reference: Solution {
+    /// Whether `input` keeps the promises of the puzzle that the fast solver
+    /// is allowed to rely on. Only valid inputs are compared.
+    fn is_valid(_input: &Self::Input) -> bool {
+        true
+    }
+
+    fn reference_part1(input: &Self::Input) -> Answer;
+    fn reference_part2(input: &Self::Input) -> Answer;
+}
@@ -0,0 +1,330 @@
+use crate::Point;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when a day's input does not have the expected shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: expected {}, found {}",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The raw puzzle text of one day, used to turn any slice of it back into a
+/// line and column when something does not parse.
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, text: &'a str) -> Self {
+        Source { day, text }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// Builds an error located at `at`, which must be a slice of this source.
+    /// An empty slice reports the position right after the preceding text.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        debug_assert!(
+            start <= at.as_ptr() as usize
+                && at.as_ptr() as usize + at.len() <= start + self.text.len(),
+            "`at` must be a slice of the source text"
+        );
+        debug_assert!(
+            self.text.is_char_boundary(at.as_ptr() as usize - start),
+            "`at` must start on a character boundary"
+        );
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(start)
+            .min(self.text.len());
+        let before = &self.text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let found = if !at.is_empty() {
+            format!("`{}`", at)
+        } else if offset == self.text.len() {
+            "end of input".to_string()
+        } else if self.text[offset..].starts_with(['\n', '\r']) {
+            "end of line".to_string()
+        } else {
+            "nothing".to_string()
+        };
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Builds an error located right after `text`, for fields that are missing.
+    pub fn error_after(&self, text: &'a str, expected: impl Into<String>) -> ParseError {
+        self.error(&text[text.len()..], expected)
+    }
+
+    /// Builds an error located at the end of the whole input.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error_after(self.text, expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, field: &str, expected: &str) -> Result<T, ParseError> {
+        field.parse().map_err(|_| self.error(field, expected))
+    }
+
+    /// Splits `field` around the first `separator`, or reports that it was
+    /// expected after the field.
+    pub fn split_once(
+        &self,
+        field: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        field
+            .split_once(separator)
+            .ok_or_else(|| self.error_after(field, format!("`{}`", separator)))
+    }
+
+    /// Parses the whitespace-separated integers of `field`. Anything else
+    /// between them is reported where it starts, rather than skipped.
+    pub fn ints<T: FromStr>(&self, field: &'a str, expected: &str) -> Result<Vec<T>, ParseError> {
+        field
+            .split_whitespace()
+            .map(|number| self.parse(number, expected))
+            .collect()
+    }
+
+    /// Like [`ints`](Self::ints), but requires exactly `N` numbers. A missing
+    /// one is reported at the end of `field`, an extra one where it starts.
+    pub fn ints_n<T: FromStr, const N: usize>(
+        &self,
+        field: &'a str,
+        expected: &str,
+    ) -> Result<[T; N], ParseError> {
+        let numbers: Vec<T> = self.ints(field, expected)?;
+        let wanted = |count| format!("{} numbers, not {}", N, count);
+        if let Some(extra) = field.split_whitespace().nth(N) {
+            return Err(self.error(extra, wanted(numbers.len())));
+        }
+        numbers
+            .try_into()
+            .map_err(|numbers: Vec<T>| self.error_after(field, wanted(numbers.len())))
+    }
+
+    /// Splits the input into the blocks of lines separated by blank lines.
+    /// Each block is a slice of the input, so errors still point at the right
+    /// line.
+    pub fn sections(&self) -> Vec<&'a str> {
+        let mut sections = Vec::new();
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in self.text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if let Some(first) = start.take() {
+                    sections.push(&self.text[first..end]);
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        if let Some(first) = start {
+            sections.push(&self.text[first..end]);
+        }
+        sections
+    }
+
+    /// Parses a `key=x,y` field such as `p=0,4`.
+    pub fn key_point<T: FromStr>(&self, field: &'a str, key: &str) -> Result<Point<T>, ParseError> {
+        let coords = self.strip_prefix(field, &format!("{}=", key))?;
+        let (x, y) = self.split_once(coords, ",")?;
+        Ok(Point::new(
+            self.parse(x, "an X coordinate")?,
+            self.parse(y, "a Y coordinate")?,
+        ))
+    }
+
+    /// Removes `prefix` from `field`, or reports that it was expected there.
+    pub fn strip_prefix(&self, field: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        field.strip_prefix(prefix).ok_or_else(|| {
+            let len = field
+                .char_indices()
+                .nth(prefix.chars().count())
+                .map_or(field.len(), |(i, _)| i);
+            self.error(&field[..len], format!("`{}`", prefix))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_reports_line_and_column_of_slice() {
+        let text = "1 2\n3 x4\n";
+        let source = Source::new(1, text);
+        let field = text
+            .lines()
+            .nth(1)
+            .unwrap()
+            .split_whitespace()
+            .nth(1)
+            .unwrap();
+        let err = source.parse::<i32>(field, "a number").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found, "`x4`");
+        assert_eq!(
+            err.to_string(),
+            "day 01, line 2, column 3: expected a number, found `x4`"
+        );
+    }
+
+    #[test]
+    fn error_after_reports_end_of_line_and_input() {
+        let text = "ab\ncd";
+        let source = Source::new(2, text);
+        let err = source.error_after(text.lines().next().unwrap(), "more");
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (1, 3, "end of line")
+        );
+        let err = source.error_at_end("more");
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (2, 3, "end of input")
+        );
+    }
+
+    #[test]
+    fn strip_prefix_points_at_the_mismatch() {
+        let text = "p=1,2 w=3,4";
+        let source = Source::new(14, text);
+        let err = source.strip_prefix(&text[6..], "v=").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (7, "`w=`"));
+        assert_eq!(source.strip_prefix(&text[..5], "p="), Ok("1,2"));
+    }
+
+    #[test]
+    fn ints_reads_whitespace_separated_numbers() {
+        let text = "94  -34\t+3 x";
+        let source = Source::new(13, text);
+        assert_eq!(
+            source.ints::<i64>(&text[..11], "a number"),
+            Ok(vec![94, -34, 3])
+        );
+        assert_eq!(source.ints::<i64>("", "a number"), Ok(vec![]));
+        assert_eq!(
+            source.ints_n::<i64, 2>(&text[..7], "a number"),
+            Ok([94, -34])
+        );
+
+        let err = source.ints::<u8>(text, "a small number").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (5, "`-34`"));
+        let err = source.ints::<i64>(text, "a number").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (12, "`x`"));
+    }
+
+    #[test]
+    fn ints_rejects_text_between_numbers() {
+        let text = "10 19zz\n3-4\n3, 4";
+        let source = Source::new(7, text);
+        let mut lines = text.lines();
+        let err = source
+            .ints::<i64>(lines.next().unwrap(), "a number")
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 4, "`19zz`"));
+        let err = source
+            .ints::<i64>(lines.next().unwrap(), "a number")
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "`3-4`"));
+        let err = source
+            .ints::<i64>(lines.next().unwrap(), "a number")
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (3, 1, "`3,`"));
+    }
+
+    #[test]
+    fn ints_n_points_at_the_missing_or_extra_number() {
+        let text = "1 2 3\n4\n";
+        let source = Source::new(1, text);
+        let mut lines = text.lines();
+        let err = source
+            .ints_n::<i64, 2>(lines.next().unwrap(), "an id")
+            .unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (5, "2 numbers, not 3"));
+        assert_eq!(err.found, "`3`");
+        let err = source
+            .ints_n::<i64, 2>(lines.next().unwrap(), "an id")
+            .unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (2, 2, "end of line")
+        );
+    }
+
+    #[test]
+    fn sections_are_split_by_blank_lines() {
+        let text = "\na|b\nc|d\r\n\r\n\n1,2\n  \n3\n";
+        let source = Source::new(5, text);
+        let sections = source.sections();
+        assert_eq!(sections, vec!["a|b\nc|d", "1,2", "3"]);
+        let err = source.parse::<u32>(sections[1], "a page");
+        assert_eq!(err.unwrap_err().line, 6);
+        assert!(Source::new(5, "\n\n").sections().is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must be a slice of the source text")]
+    fn error_rejects_text_from_elsewhere() {
+        let elsewhere = String::from("x4");
+        Source::new(1, "1 2\n3 x4\n").error(&elsewhere, "a number");
+    }
+
+    #[test]
+    fn split_once_and_key_point() {
+        let text = "190: 10 19\np=0,4 v=3,-3";
+        let source = Source::new(7, text);
+        assert_eq!(source.split_once(&text[..10], ": "), Ok(("190", "10 19")));
+        let err = source.split_once(&text[5..10], ": ").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (11, "`: `"));
+
+        assert_eq!(source.key_point(&text[11..16], "p"), Ok(Point::new(0, 4)));
+        assert_eq!(source.key_point(&text[17..], "v"), Ok(Point::new(3, -3)));
+        let err = source.key_point::<i32>(&text[17..], "p").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+
+        let text = "p=0,4\np=1";
+        let source = Source::new(14, text);
+        let err = source.key_point::<i32>(&text[6..], "p").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.expected, "`,`");
+    }
+}
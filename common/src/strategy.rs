@@ -0,0 +1,13 @@
+//! Proptest strategies shared by the property tests of the day crates.
+
+use proptest::prelude::*;
+
+/// The rows of a rectangular grid from 1×1 to 19×19, every cell one of the
+/// characters of the regex class `cells`, such as `"[.#]"`.
+pub fn grid_rows(cells: &str) -> impl Strategy<Value = Vec<String>> {
+    let cells = cells.to_string();
+    (1..20usize, 1..20usize).prop_flat_map(move |(width, height)| {
+        let row = prop::string::string_regex(&format!("{}{{{}}}", cells, width)).unwrap();
+        prop::collection::vec(row, height)
+    })
+}
@@ -0,0 +1,348 @@
+//! Any number of location lists side by side, compared pairwise with a
+//! choice of metrics. The puzzle is the two-column case, with [`L1`] for
+//! part 1 and [`Similarity`] for part 2.
+
+use crate::{Day01, Id};
+use common::{ParseError, Solution, Source};
+use std::error::Error;
+use std::fmt;
+
+/// One of the lists, sorted, with its position among the columns.
+#[derive(Clone, Copy, Debug)]
+pub struct Column<'a, T> {
+    pub index: usize,
+    pub ids: &'a [T],
+}
+
+/// A way to compare two lists of the same length.
+pub trait Metric<T: Id> {
+    type Value;
+
+    fn compare(&self, left: Column<T>, right: Column<T>) -> Self::Value;
+}
+
+/// A comparison too large for the type it is computed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetricOverflow {
+    pub metric: &'static str,
+    pub left: usize,
+    pub right: usize,
+    pub width: &'static str,
+}
+
+impl MetricOverflow {
+    fn of<W, T>(metric: &'static str, left: Column<T>, right: Column<T>) -> Self {
+        MetricOverflow {
+            metric,
+            left: left.index,
+            right: right.index,
+            width: std::any::type_name::<W>(),
+        }
+    }
+}
+
+impl fmt::Display for MetricOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} of columns {} and {} does not fit in {}",
+            self.metric, self.left, self.right, self.width
+        )
+    }
+}
+
+impl Error for MetricOverflow {}
+
+/// Sum of the differences between the ids of the same rank.
+pub struct L1;
+
+/// Euclidean distance between the lists, ids paired by rank.
+pub struct L2;
+
+/// Largest difference between two ids of the same rank.
+pub struct Max;
+
+/// Every id of the left list times the number of times it is in the right
+/// one. Unlike the distances it is not symmetric, and it is summed in `i128`
+/// since every id can be counted once per id of the other list; only ids of
+/// that width can still overflow it.
+pub struct Similarity;
+
+fn differences<'a, T: Id>(
+    left: Column<'a, T>,
+    right: Column<'a, T>,
+) -> impl Iterator<Item = T::Distance> + 'a {
+    left.ids.iter().zip(right.ids).map(|(&l, &r)| l.distance(r))
+}
+
+impl<T: Id> Metric<T> for L1 {
+    type Value = Result<T::Distance, MetricOverflow>;
+
+    fn compare(&self, left: Column<T>, right: Column<T>) -> Self::Value {
+        differences(left, right)
+            .try_fold(T::Distance::ZERO, |total, d| total.checked_add(d))
+            .ok_or_else(|| MetricOverflow::of::<T::Distance, T>("L1 distance", left, right))
+    }
+}
+
+impl<T: Id> Metric<T> for L2 {
+    type Value = f64;
+
+    fn compare(&self, left: Column<T>, right: Column<T>) -> f64 {
+        // the square of a difference does not always fit in an integer
+        differences(left, right)
+            .map(|d| d.to_f64().powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl<T: Id> Metric<T> for Max {
+    type Value = T::Distance;
+
+    fn compare(&self, left: Column<T>, right: Column<T>) -> T::Distance {
+        differences(left, right).max().unwrap_or(T::Distance::ZERO)
+    }
+}
+
+impl<T: Id> Metric<T> for Similarity
+where
+    i128: From<T>,
+{
+    type Value = Result<i128, MetricOverflow>;
+
+    fn compare(&self, left: Column<T>, right: Column<T>) -> Self::Value {
+        // both lists are sorted, so the copies of an id in `right` are found
+        // by moving forward only, and counted once for repeated left ids
+        let overflow = || MetricOverflow::of::<i128, T>("similarity", left, right);
+        let ids = right.ids;
+        let mut total: i128 = 0;
+        let mut i = 0;
+        let mut counted: Option<(T, i128)> = None;
+        for &id in left.ids {
+            let count = match counted {
+                Some((last, count)) if last == id => count,
+                _ => {
+                    while i < ids.len() && ids[i] < id {
+                        i += 1;
+                    }
+                    let start = i;
+                    while i < ids.len() && ids[i] == id {
+                        i += 1;
+                    }
+                    (i - start) as i128
+                }
+            };
+            counted = Some((id, count));
+            total = i128::from(id)
+                .checked_mul(count)
+                .and_then(|score| total.checked_add(score))
+                .ok_or_else(overflow)?;
+        }
+        Ok(total)
+    }
+}
+
+/// Lists of the same length, each kept sorted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Columns<T: Id = i32> {
+    sorted: Vec<Vec<T>>,
+}
+
+impl<T: Id> Columns<T> {
+    /// # Panics
+    ///
+    /// If the lists do not all have the same length.
+    pub fn new(mut lists: Vec<Vec<T>>) -> Self {
+        assert!(
+            lists.windows(2).all(|pair| pair[0].len() == pair[1].len()),
+            "lists of different lengths cannot be compared by rank"
+        );
+        for list in &mut lists {
+            list.sort_unstable();
+        }
+        Columns { sorted: lists }
+    }
+
+    /// Reads one list per column of whitespace-separated ids. Every line
+    /// needs as many ids as the first one.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let source = Source::new(Day01::DAY, input);
+        let mut lists: Vec<Vec<T>> = Vec::new();
+        for line in source.lines() {
+            let ids: Vec<T> = source.ints(line, "a location id")?;
+            if lists.is_empty() {
+                if ids.is_empty() {
+                    return Err(source.error(line, "a location id"));
+                }
+                lists = vec![Vec::new(); ids.len()];
+            } else if ids.len() != lists.len() {
+                let wanted = format!("{} numbers, not {}", lists.len(), ids.len());
+                return Err(source.error(line, wanted));
+            }
+            for (list, id) in lists.iter_mut().zip(ids) {
+                list.push(id);
+            }
+        }
+        Ok(Columns::new(lists))
+    }
+
+    /// Number of lists.
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// The ids of list `i`, in ascending order.
+    pub fn sorted(&self, i: usize) -> &[T] {
+        &self.sorted[i]
+    }
+
+    fn column(&self, index: usize) -> Column<'_, T> {
+        Column {
+            index,
+            ids: &self.sorted[index],
+        }
+    }
+
+    /// Compares list `left` with list `right`.
+    pub fn compare<M: Metric<T>>(&self, metric: &M, left: usize, right: usize) -> M::Value {
+        metric.compare(self.column(left), self.column(right))
+    }
+
+    /// Every list compared with every other one: row `i`, column `j` holds
+    /// the comparison of list `i` with list `j`.
+    pub fn matrix<M: Metric<T>>(&self, metric: &M) -> Vec<Vec<M::Value>> {
+        (0..self.count())
+            .map(|left| {
+                (0..self.count())
+                    .map(|right| self.compare(metric, left, right))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const THREE: &str = "3 4 1\n4 3 1\n2 5 9\n1 3 1\n3 9 2\n3 3 3\n";
+
+    fn unwrap<V>(matrix: Vec<Vec<Result<V, MetricOverflow>>>) -> Vec<Vec<V>> {
+        matrix
+            .into_iter()
+            .map(|row| row.into_iter().map(Result::unwrap).collect())
+            .collect()
+    }
+
+    #[test]
+    fn the_puzzle_is_two_columns() {
+        let columns = Columns::<i32>::parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(columns.count(), 2);
+        assert_eq!(columns.compare(&L1, 0, 1), Ok(11));
+        assert_eq!(columns.compare(&Similarity, 0, 1), Ok(31));
+    }
+
+    #[test]
+    fn matrices_of_three_columns() {
+        let columns = Columns::<i32>::parse(THREE).unwrap();
+        assert_eq!(columns.sorted(2), [1, 1, 1, 2, 3, 9]);
+        assert_eq!(
+            unwrap(columns.matrix(&L1)),
+            [[0, 11, 9], [11, 0, 10], [9, 10, 0]]
+        );
+        assert_eq!(columns.matrix(&Max), [[0, 5, 5], [5, 0, 2], [5, 2, 0]]);
+        assert_eq!(
+            unwrap(columns.matrix(&Similarity)),
+            [[34, 31, 14], [31, 45, 18], [14, 18, 23]]
+        );
+        let l2 = columns.matrix(&L2);
+        assert_eq!(l2[0][0], 0.0);
+        assert_eq!(l2[0][1], l2[1][0]);
+        assert!((l2[0][2] - 31f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn extreme_ids_do_not_overflow() {
+        let columns = Columns::new(vec![vec![i32::MIN; 2], vec![i32::MAX; 2]]);
+        let d = u32::MAX as f64;
+        assert_eq!(columns.compare(&L2, 0, 1), (2.0 * d * d).sqrt());
+        assert_eq!(columns.compare(&Max, 0, 1), u32::MAX as i64);
+        assert_eq!(columns.compare(&L1, 0, 1), Ok(2 * u32::MAX as i64));
+    }
+
+    #[test]
+    fn ids_of_any_width() {
+        let columns = Columns::new(vec![vec![i64::MIN], vec![i64::MAX]]);
+        assert_eq!(columns.compare(&Max, 0, 1), u64::MAX as i128);
+        assert_eq!(columns.compare(&L1, 1, 0), Ok(u64::MAX as i128));
+        let columns = Columns::new(vec![vec![i128::MIN], vec![i128::MAX]]);
+        assert_eq!(columns.compare(&Max, 0, 1), u128::MAX);
+    }
+
+    #[test]
+    fn overflow_names_the_metric_and_the_columns() {
+        let columns = Columns::new(vec![
+            vec![0, 0],
+            vec![i128::MIN, 0],
+            vec![i128::MAX, i128::MAX],
+        ]);
+        assert_eq!(columns.compare(&L1, 0, 1), Ok(1 << 127));
+        let err = columns.compare(&L1, 1, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "L1 distance of columns 1 and 2 does not fit in u128"
+        );
+        let err = columns.compare(&Similarity, 2, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "similarity of columns 2 and 2 does not fit in i128"
+        );
+    }
+
+    #[test]
+    fn repeated_ids_are_counted_once() {
+        let columns = Columns::new(vec![vec![5; 100_000], vec![5; 100_000]]);
+        assert_eq!(
+            columns.compare(&Similarity, 0, 1),
+            Ok(5 * 100_000i128.pow(2))
+        );
+        let columns = Columns::new(vec![vec![1, 2, 2, 7, 7], vec![2, 2, 3, 7, 7]]);
+        assert_eq!(
+            columns.compare(&Similarity, 0, 1),
+            Ok(2 * 2 * 2 + 7 * 2 * 2)
+        );
+    }
+
+    #[test]
+    fn every_line_has_as_many_ids_as_the_first() {
+        let err = Columns::<i32>::parse("1 2 3\n4 5\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "3 numbers, not 2"));
+        let err = Columns::<i32>::parse("\n1 2\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "a location id"));
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn lists_must_have_the_same_length() {
+        Columns::new(vec![vec![1, 2], vec![3]]);
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_both_parts(
+            pairs in prop::collection::vec((-1000..1000i32, -50..50i32), 0..100),
+        ) {
+            let lists = (
+                pairs.iter().map(|p| p.0).collect::<Vec<_>>(),
+                pairs.iter().map(|p| p.1).collect::<Vec<_>>(),
+            );
+            let columns = Columns::new(vec![lists.0.clone(), lists.1.clone()]);
+            prop_assert_eq!(columns.compare(&L1, 0, 1)?, crate::distance::<_, i64>(&lists)?);
+            prop_assert_eq!(columns.compare(&Similarity, 0, 1)?, crate::similarity::<_, i128>(&lists)?);
+            prop_assert_eq!(columns.compare(&L1, 1, 0), columns.compare(&L1, 0, 1));
+            prop_assert!(columns.compare(&Max, 0, 1) <= columns.compare(&L1, 0, 1)?);
+        }
+    }
+}
@@ -0,0 +1,296 @@
+pub mod columns;
+pub mod stream;
+
+pub use common::Overflow;
+
+use common::{Answer, ParseError, Solution, SolveError, Source};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// An integer type the lists can be read in, or their answers summed in.
+/// Every operation is checked, so that an answer too large for the type is
+/// reported instead of wrapping around.
+pub trait Id: Copy + Ord + Hash + FromStr + fmt::Debug {
+    const ZERO: Self;
+
+    /// A type the difference between any two ids fits in.
+    type Distance: Id;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+    fn from_count(count: usize) -> Option<Self>;
+    fn distance(self, other: Self) -> Self::Distance;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_id {
+    ($($t:ty => $distance:ty),+) => {
+        $(
+            impl Id for $t {
+                const ZERO: Self = 0;
+
+                type Distance = $distance;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t>::checked_abs(self)
+                }
+
+                fn from_count(count: usize) -> Option<Self> {
+                    count.try_into().ok()
+                }
+
+                fn distance(self, other: Self) -> $distance {
+                    self.abs_diff(other) as $distance
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )+
+    };
+}
+
+impl_id!(i32 => i64, i64 => i128, i128 => u128);
+
+/// Only there as the distance between two `i128` ids.
+impl Id for u128 {
+    const ZERO: Self = 0;
+
+    type Distance = u128;
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u128::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u128::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u128::checked_mul(self, other)
+    }
+
+    fn checked_abs(self) -> Option<Self> {
+        Some(self)
+    }
+
+    fn from_count(count: usize) -> Option<Self> {
+        count.try_into().ok()
+    }
+
+    fn distance(self, other: Self) -> u128 {
+        self.abs_diff(other)
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+pub type Lists<T> = (Vec<T>, Vec<T>);
+
+fn parse_line<T: FromStr>(source: Source, line: &str) -> Result<[T; 2], ParseError> {
+    source.ints_n(line, "a location id")
+}
+
+/// Reads both lists, with ids of type `T`; an id too large for it does not
+/// parse.
+pub fn read_lists<T: Id>(input: &str) -> Result<Lists<T>, ParseError> {
+    let source = Source::new(Day01::DAY, input);
+    let mut first_list = Vec::new();
+    let mut second_list = Vec::new();
+
+    for line in source.lines() {
+        let [left, right] = parse_line(source, line)?;
+        first_list.push(left);
+        second_list.push(right);
+    }
+    Ok((first_list, second_list))
+}
+
+/// Part 1, with ids of type `T` and the distance summed in `W`.
+pub fn distance<T: Id, W: Id + From<T>>(
+    (first_list, second_list): &Lists<T>,
+) -> Result<W, Overflow> {
+    let mut l1_sorted = first_list.to_vec();
+    let mut l2_sorted = second_list.to_vec();
+
+    l1_sorted.sort_unstable();
+    l2_sorted.sort_unstable();
+
+    l1_sorted
+        .iter()
+        .zip(l2_sorted.iter())
+        .try_fold(W::ZERO, |total, (&a, &b)| {
+            W::from(a)
+                .checked_sub(W::from(b))
+                .and_then(W::checked_abs)
+                .and_then(|difference| total.checked_add(difference))
+        })
+        .ok_or(Overflow::of::<W>(Day01::DAY, 1))
+}
+
+/// Part 2, with ids of type `T` and the similarity summed in `W`.
+pub fn similarity<T: Id, W: Id + From<T>>(
+    (first_list, second_list): &Lists<T>,
+) -> Result<W, Overflow> {
+    let right_counts: HashMap<T, usize> =
+        second_list.iter().fold(HashMap::new(), |mut acc, &num| {
+            *acc.entry(num).or_insert(0) += 1;
+            acc
+        });
+
+    first_list
+        .iter()
+        .try_fold(W::ZERO, |total, &left_num| {
+            let count = right_counts.get(&left_num).cloned().unwrap_or(0);
+            W::from_count(count)
+                .and_then(|count| W::from(left_num).checked_mul(count))
+                .and_then(|score| total.checked_add(score))
+        })
+        .ok_or(Overflow::of::<W>(Day01::DAY, 2))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Lists<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_lists(input)
+    }
+
+    fn part1(lists: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(distance::<_, i64>(lists)?.into())
+    }
+
+    fn part2(lists: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(similarity::<_, i64>(lists)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day01 as Solution>::Input {
+        Day01::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(distance::<_, i64>(&example()), Ok(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(similarity::<_, i64>(&example()), Ok(31));
+    }
+
+    #[test]
+    fn distance_overflows_only_when_too_narrow() {
+        let lists = (vec![i32::MIN], vec![i32::MAX]);
+        assert_eq!(
+            distance::<_, i32>(&lists),
+            Err(Overflow::of::<i32>(Day01::DAY, 1))
+        );
+        assert_eq!(distance::<_, i64>(&lists), Ok(u32::MAX as i64));
+        // each difference fits, their sum does not
+        let lists = (vec![0, 0], vec![1, i32::MAX]);
+        assert!(distance::<_, i32>(&lists).is_err());
+        assert_eq!(distance::<_, i64>(&lists), Ok(i32::MAX as i64 + 1));
+        // the difference itself is fine, its absolute value is not
+        let lists = (vec![i32::MIN], vec![0]);
+        assert!(distance::<_, i32>(&lists).is_err());
+        let lists = (vec![i32::MIN + 1], vec![0]);
+        assert_eq!(distance::<_, i32>(&lists), Ok(i32::MAX));
+    }
+
+    #[test]
+    fn similarity_overflows_only_when_too_narrow() {
+        let lists = (vec![i32::MAX], vec![i32::MAX]);
+        assert_eq!(similarity::<_, i32>(&lists), Ok(i32::MAX));
+        let lists = (vec![i32::MAX, i32::MAX], vec![i32::MAX]);
+        assert_eq!(
+            similarity::<_, i32>(&lists),
+            Err(Overflow::of::<i32>(Day01::DAY, 2))
+        );
+        assert_eq!(similarity::<_, i64>(&lists), Ok(2 * i32::MAX as i64));
+        let lists = (vec![i64::MAX], vec![i64::MAX, i64::MAX]);
+        assert!(similarity::<_, i64>(&lists).is_err());
+        assert_eq!(similarity::<_, i128>(&lists), Ok(2 * i64::MAX as i128));
+    }
+
+    #[test]
+    fn answers_beyond_i64_are_errors() {
+        // 65537 * 65537 * i32::MAX is just above i64::MAX
+        let lists = (vec![i32::MAX; 65537], vec![i32::MAX; 65537]);
+        assert_eq!(Day01::part1(&lists).unwrap(), Answer::Signed(0));
+        let err = Day01::part2(&lists).unwrap_err();
+        assert_eq!(err.to_string(), "day 01 part 2 does not fit in i64");
+    }
+
+    #[test]
+    fn ids_are_read_in_the_requested_width() {
+        let text = "3000000000   -3000000000\n";
+        let err = read_lists::<i32>(text).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (1, "a location id"));
+        let lists = read_lists::<i64>(text).unwrap();
+        assert_eq!(distance::<_, i64>(&lists), Ok(6_000_000_000));
+    }
+
+    #[test]
+    fn parse_requires_two_ids_per_line() {
+        let err = Day01::parse("3   4\n4\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "2 numbers, not 1"));
+        assert_eq!((err.column, err.found.as_str()), (2, "end of line"));
+        let err = Day01::parse("3   4   5\n").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (9, "2 numbers, not 3"));
+    }
+
+    #[test]
+    fn parse_rejects_text_between_ids() {
+        let err = Day01::parse("3   4\n3 foo 4\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "`foo`"));
+        let err = Day01::parse("3,4\n").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (1, "a location id"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            pairs in prop::collection::vec((0..100_000i32, 0..100_000i32), 1..50),
+        ) {
+            let text: String = pairs.iter().map(|(a, b)| format!("{}   {}\n", a, b)).collect();
+            let (first_list, second_list) = Day01::parse(&text).unwrap();
+            prop_assert_eq!(first_list, pairs.iter().map(|p| p.0).collect::<Vec<_>>());
+            prop_assert_eq!(second_list, pairs.iter().map(|p| p.1).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9 \n-]{0,40}") {
+            let _ = Day01::parse(&text);
+        }
+    }
+}
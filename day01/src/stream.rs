@@ -0,0 +1,365 @@
+//! Both parts computed from a reader in bounded memory, for lists far larger
+//! than the puzzle's. Each column is sorted externally: chunks are sorted in
+//! memory and written to temporary run files, which are merged back while
+//! the answers are computed.
+
+use crate::{parse_line, Day01, Overflow};
+use common::{ParseError, Solution, Source};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Pairs of ids held in memory before a chunk is sorted and written out:
+/// 8 MiB for both columns.
+pub const DEFAULT_CHUNK: usize = 1 << 20;
+/// Runs merged at once. Every time a column has this many runs of the same
+/// length they are merged into a longer one, which bounds the open files.
+const MAX_FAN_IN: usize = 64;
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+type Ids<'a> = Box<dyn Iterator<Item = io::Result<i32>> + 'a>;
+
+/// The ids of a run file, in the order they were written.
+fn read_run(path: &Path) -> io::Result<Ids<'static>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    Ok(Box::new(std::iter::from_fn(move || {
+        let mut bytes = [0; 4];
+        match reader.read_exact(&mut bytes) {
+            Ok(()) => Some(Ok(i32::from_le_bytes(bytes))),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => None,
+            Err(err) => Some(Err(err)),
+        }
+    })))
+}
+
+fn write_run(path: &Path, ids: impl IntoIterator<Item = io::Result<i32>>) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for id in ids {
+        writer.write_all(&id?.to_le_bytes())?;
+    }
+    writer.flush()
+}
+
+/// Merges sorted sequences into one sorted sequence.
+struct Merge<'a> {
+    sources: Vec<Ids<'a>>,
+    heap: BinaryHeap<Reverse<(i32, usize)>>,
+}
+
+impl<'a> Merge<'a> {
+    fn new(mut sources: Vec<Ids<'a>>) -> io::Result<Self> {
+        let mut heap = BinaryHeap::new();
+        for (i, source) in sources.iter_mut().enumerate() {
+            if let Some(id) = source.next() {
+                heap.push(Reverse((id?, i)));
+            }
+        }
+        Ok(Merge { sources, heap })
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((id, i)) = self.heap.pop()?;
+        match self.sources[i].next() {
+            Some(Ok(next)) => self.heap.push(Reverse((next, i))),
+            Some(Err(err)) => return Some(Err(err)),
+            None => {}
+        }
+        Some(Ok(id))
+    }
+}
+
+/// One column being sorted: full chunks live in run files, the last one
+/// stays in memory.
+struct Column {
+    name: &'static str,
+    limit: usize,
+    chunk: Vec<i32>,
+    // `levels[k]` holds runs made of `MAX_FAN_IN.pow(k)` chunks, so that
+    // every id is merged only a logarithmic number of times
+    levels: Vec<Vec<PathBuf>>,
+    written: usize,
+}
+
+impl Column {
+    fn new(name: &'static str, limit: usize) -> Self {
+        Column {
+            name,
+            limit,
+            chunk: Vec::with_capacity(limit),
+            levels: Vec::new(),
+            written: 0,
+        }
+    }
+
+    fn push(&mut self, id: i32, dir: &Path) -> io::Result<()> {
+        self.chunk.push(id);
+        if self.chunk.len() == self.limit {
+            self.chunk.sort_unstable();
+            let path = self.next_run(dir);
+            write_run(&path, self.chunk.iter().map(|&id| Ok(id)))?;
+            self.chunk.clear();
+            self.add_run(dir, 0, path)?;
+        }
+        Ok(())
+    }
+
+    fn next_run(&mut self, dir: &Path) -> PathBuf {
+        self.written += 1;
+        dir.join(format!("{}-{}", self.name, self.written))
+    }
+
+    fn add_run(&mut self, dir: &Path, level: usize, path: PathBuf) -> io::Result<()> {
+        if self.levels.len() == level {
+            self.levels.push(Vec::new());
+        }
+        self.levels[level].push(path);
+        if self.levels[level].len() == MAX_FAN_IN {
+            let runs = std::mem::take(&mut self.levels[level]);
+            let sources = runs
+                .iter()
+                .map(|run| read_run(run))
+                .collect::<io::Result<_>>()?;
+            let merged = self.next_run(dir);
+            write_run(&merged, Merge::new(sources)?)?;
+            for run in runs {
+                fs::remove_file(run)?;
+            }
+            self.add_run(dir, level + 1, merged)?;
+        }
+        Ok(())
+    }
+
+    /// Sorts what is left in memory; no id can be pushed afterwards.
+    fn finish(&mut self) {
+        self.chunk.sort_unstable();
+    }
+
+    fn runs(&self) -> impl Iterator<Item = &PathBuf> {
+        self.levels.iter().flatten()
+    }
+
+    /// Every id of the column, in ascending order.
+    fn sorted(&self) -> io::Result<Merge<'_>> {
+        let mut sources = self
+            .runs()
+            .map(|run| read_run(run))
+            .collect::<io::Result<Vec<_>>>()?;
+        sources.push(Box::new(self.chunk.iter().map(|&id| Ok(id))));
+        Merge::new(sources)
+    }
+}
+
+/// Both lists, sorted on disk. The run files are removed on drop.
+pub struct SortedLists {
+    left: Column,
+    right: Column,
+    _dir: TempDir,
+}
+
+impl SortedLists {
+    /// Reads `left right` lines, keeping at most `chunk` pairs in memory.
+    pub fn read(mut reader: impl BufRead, chunk: usize) -> Result<Self, StreamError> {
+        let dir = tempfile::Builder::new().prefix("day01-").tempdir()?;
+        let chunk = chunk.max(1);
+        let mut left = Column::new("left", chunk);
+        let mut right = Column::new("right", chunk);
+        let mut line = String::new();
+        let mut number = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            number += 1;
+            let text = line.trim_end_matches(['\n', '\r']);
+            let [l, r] = parse_line(Source::new(Day01::DAY, text), text).map_err(|mut err| {
+                err.line = number;
+                err
+            })?;
+            left.push(l, dir.path())?;
+            right.push(r, dir.path())?;
+        }
+        left.finish();
+        right.finish();
+        Ok(SortedLists {
+            left,
+            right,
+            _dir: dir,
+        })
+    }
+
+    /// Part 1: the distance between the ids of the same rank in both lists.
+    /// A total beyond `i64` is an error of kind `Other` wrapping [`Overflow`].
+    pub fn distance(&self) -> io::Result<i64> {
+        let mut total: i64 = 0;
+        for (l, r) in self.left.sorted()?.zip(self.right.sorted()?) {
+            total = total
+                .checked_add((l? as i64 - r? as i64).abs())
+                .ok_or_else(|| overflow(1))?;
+        }
+        Ok(total)
+    }
+
+    /// Part 2: every left id times the number of times it is in the right
+    /// list. Walking both sorted lists side by side counts each right id as
+    /// the left ids reach it.
+    pub fn similarity(&self) -> io::Result<i64> {
+        let mut right = self.right.sorted()?.peekable();
+        let mut total: i64 = 0;
+        let mut counted: Option<(i32, i64)> = None;
+        for id in self.left.sorted()? {
+            let id = id?;
+            let count = match counted {
+                Some((last, count)) if last == id => count,
+                _ => count_equal(&mut right, id)?,
+            };
+            counted = Some((id, count));
+            total = (id as i64)
+                .checked_mul(count)
+                .and_then(|score| total.checked_add(score))
+                .ok_or_else(|| overflow(2))?;
+        }
+        Ok(total)
+    }
+}
+
+fn overflow(part: u8) -> io::Error {
+    io::Error::other(Overflow::of::<i64>(Day01::DAY, part))
+}
+
+/// Skips the ids below `id` and counts the ones equal to it.
+fn count_equal(ids: &mut Peekable<Merge>, id: i32) -> io::Result<i64> {
+    let mut count = 0;
+    loop {
+        match ids.peek() {
+            Some(Ok(next)) if *next <= id => {
+                count += (*next == id) as i64;
+                ids.next();
+            }
+            Some(Err(_)) => return Err(ids.next().unwrap().unwrap_err()),
+            _ => return Ok(count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn solve(text: &str, chunk: usize) -> (i64, i64) {
+        let lists = SortedLists::read(text.as_bytes(), chunk).unwrap();
+        (lists.distance().unwrap(), lists.similarity().unwrap())
+    }
+
+    #[test]
+    fn solves_the_example() {
+        let example = include_str!("../example.txt");
+        assert_eq!(solve(example, DEFAULT_CHUNK), (11, 31));
+        assert_eq!(solve(example, 2), (11, 31));
+        assert_eq!(solve(example, 1), (11, 31));
+    }
+
+    #[test]
+    fn many_runs_are_merged_early() {
+        let text: String = (0..300)
+            .map(|i| format!("{} {}\n", 300 - i, i % 7))
+            .collect();
+        let lists = SortedLists::read(text.as_bytes(), 1).unwrap();
+        // 4 runs of 64 chunks and 44 single chunks
+        assert_eq!(lists.left.levels[1].len(), 4);
+        assert_eq!(lists.left.runs().count(), 48);
+        let files = fs::read_dir(lists._dir.path()).unwrap().count();
+        assert_eq!(files, 2 * 48);
+        let left: Vec<i32> = lists.left.sorted().unwrap().map(Result::unwrap).collect();
+        assert_eq!(left, (1..=300).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn run_files_are_removed() {
+        let lists = SortedLists::read("1 2\n3 4\n5 6\n".as_bytes(), 1).unwrap();
+        let dir = lists._dir.path().to_path_buf();
+        assert!(dir.exists());
+        drop(lists);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let Err(StreamError::Parse(err)) = SortedLists::read("1 2\r\n3 4\r\n5\r\n".as_bytes(), 1)
+        else {
+            panic!("the third line is incomplete");
+        };
+        assert_eq!((err.line, err.expected.as_str()), (3, "2 numbers, not 1"));
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let line = format!("{} {}\n", i32::MAX, i32::MAX);
+        let lists = SortedLists::read(line.repeat(65537).as_bytes(), DEFAULT_CHUNK).unwrap();
+        assert_eq!(lists.distance().unwrap(), 0);
+        let err = lists.similarity().unwrap_err();
+        assert_eq!(err.to_string(), "day 01 part 2 does not fit in i64");
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_the_lists_in_memory(
+            pairs in prop::collection::vec((-1000..1000i32, -50..50i32), 0..200),
+            chunk in 1..10usize,
+        ) {
+            let text: String = pairs.iter().map(|(a, b)| format!("{}   {}\n", a, b)).collect();
+            let lists = Day01::parse(&text).unwrap();
+            let expected = (
+                crate::distance(&lists).unwrap(),
+                crate::similarity(&lists).unwrap(),
+            );
+            prop_assert_eq!(solve(&text, chunk), expected);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Why a report is unsafe, and which level the Problem Dampener would have to
+//! remove, for looking into reports one at a time.
+
+use crate::dampener::Rules;
+use crate::Day02;
+use common::{ParseError, Solution};
+use std::fmt;
+
+/// What is wrong with two adjacent levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// The levels go the other way than the first two that differ did.
+    DirectionChange,
+    /// The levels differ by more than `max_step`.
+    StepTooLarge { max_step: u32 },
+    /// The levels differ, but by less than `min_step`.
+    StepTooSmall { min_step: u32 },
+    /// The levels are equal, and the rules do not allow it.
+    ZeroStep,
+}
+
+/// The first adjacent levels that break the rules, at `index` and
+/// `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "levels {} and {} ", self.index, self.index + 1)?;
+        match self.problem {
+            Problem::DirectionChange => write!(f, "change direction"),
+            Problem::StepTooLarge { max_step } => write!(f, "differ by more than {}", max_step),
+            Problem::StepTooSmall { min_step } => write!(f, "differ by less than {}", min_step),
+            Problem::ZeroStep => write!(f, "are equal"),
+        }
+    }
+}
+
+/// Finds the first adjacent levels that break `rules`. The direction is set
+/// by the first two adjacent levels that differ, so a report is safe exactly
+/// when there is none.
+pub fn first_violation(levels: &[i32], rules: &Rules) -> Option<Violation> {
+    let increasing = levels
+        .windows(2)
+        .find(|pair| pair[0] != pair[1])
+        .is_some_and(|pair| pair[0] < pair[1]);
+    levels.windows(2).enumerate().find_map(|(index, pair)| {
+        let step = pair[1] as i64 - pair[0] as i64;
+        let problem = if step == 0 {
+            if rules.allow_equal {
+                return None;
+            }
+            Problem::ZeroStep
+        } else if (step > 0) != increasing {
+            Problem::DirectionChange
+        } else if step.abs() > rules.max_step as i64 {
+            Problem::StepTooLarge {
+                max_step: rules.max_step,
+            }
+        } else if step.abs() < rules.min_step as i64 {
+            Problem::StepTooSmall {
+                min_step: rules.min_step,
+            }
+        } else {
+            return None;
+        };
+        Some(Violation { index, problem })
+    })
+}
+
+/// The first level whose removal leaves a report that follows `rules`. Going
+/// either way, the level to remove is one of the first pair that does not
+/// follow the rules that way, so only those few are tried.
+fn removal(levels: &[i32], rules: &Rules) -> Option<usize> {
+    let mut candidates: Vec<usize> = [true, false]
+        .into_iter()
+        .filter_map(|increasing| {
+            levels
+                .windows(2)
+                .position(|pair| !rules.allows(pair[0], pair[1], increasing))
+        })
+        .flat_map(|index| [index, index + 1])
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .find(|&i| rules.is_safe(&[&levels[..i], &levels[i + 1..]].concat(), 0))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportAnalysis {
+    /// `None` when the report is safe.
+    pub violation: Option<Violation>,
+    /// For an unsafe report, the first level whose removal makes it safe.
+    pub removal: Option<usize>,
+}
+
+impl ReportAnalysis {
+    pub fn new(levels: &[i32], rules: &Rules) -> Self {
+        let violation = first_violation(levels, rules);
+        ReportAnalysis {
+            violation,
+            removal: violation.and_then(|_| removal(levels, rules)),
+        }
+    }
+
+    pub fn is_safe(&self) -> bool {
+        self.violation.is_none()
+    }
+
+    /// Whether the report is safe once the Problem Dampener removed a level.
+    pub fn is_safe_with_dampener(&self) -> bool {
+        self.is_safe() || self.removal.is_some()
+    }
+}
+
+impl fmt::Display for ReportAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(violation) = self.violation else {
+            return write!(f, "safe");
+        };
+        write!(f, "unsafe, {}; ", violation)?;
+        match self.removal {
+            Some(index) => write!(f, "safe without level {}", index),
+            None => write!(f, "no single removal makes it safe"),
+        }
+    }
+}
+
+/// Every report of `input` with its analysis under the puzzle's rules, one
+/// line each. Levels are counted from 0.
+pub fn explain(input: &str) -> Result<String, ParseError> {
+    let reports = Day02::parse(input)?;
+    Ok(reports
+        .iter()
+        .enumerate()
+        .map(|(i, levels)| {
+            let text: Vec<String> = levels.iter().map(ToString::to_string).collect();
+            let analysis = ReportAnalysis::new(levels, &Rules::PUZZLE);
+            format!("line {}: {}: {}\n", i + 1, text.join(" "), analysis)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn violation(index: usize, problem: Problem) -> Option<Violation> {
+        Some(Violation { index, problem })
+    }
+
+    fn first_violation_puzzle(levels: &[i32]) -> Option<Violation> {
+        first_violation(levels, &Rules::PUZZLE)
+    }
+
+    fn puzzle(levels: &[i32]) -> ReportAnalysis {
+        ReportAnalysis::new(levels, &Rules::PUZZLE)
+    }
+
+    #[test]
+    fn names_the_first_offending_pair() {
+        assert_eq!(first_violation_puzzle(&[7, 6, 4, 2, 1]), None);
+        assert_eq!(
+            first_violation_puzzle(&[1, 2, 7, 8, 9]),
+            violation(1, Problem::StepTooLarge { max_step: 3 })
+        );
+        assert_eq!(
+            first_violation_puzzle(&[1, 3, 2, 4, 5]),
+            violation(1, Problem::DirectionChange)
+        );
+        assert_eq!(
+            first_violation_puzzle(&[8, 6, 4, 4, 1]),
+            violation(2, Problem::ZeroStep)
+        );
+        assert_eq!(
+            first_violation_puzzle(&[3, 3]),
+            violation(0, Problem::ZeroStep)
+        );
+        assert_eq!(first_violation_puzzle(&[5]), None);
+        assert_eq!(first_violation_puzzle(&[]), None);
+    }
+
+    #[test]
+    fn says_which_removal_fixes_the_report() {
+        assert_eq!(puzzle(&[7, 6, 4, 2, 1]).removal, None);
+        assert_eq!(puzzle(&[1, 3, 2, 4, 5]).removal, Some(1));
+        assert_eq!(puzzle(&[8, 6, 4, 4, 1]).removal, Some(2));
+        assert_eq!(puzzle(&[9, 7, 6, 2, 1]).removal, None);
+        // the offending pair is not always next to the level to remove
+        let analysis = puzzle(&[5, 6, 4, 3, 2]);
+        assert_eq!(analysis.violation, violation(1, Problem::DirectionChange));
+        assert_eq!(analysis.removal, Some(0));
+        assert!(!analysis.is_safe());
+        assert!(analysis.is_safe_with_dampener());
+    }
+
+    #[test]
+    fn explains_every_line() {
+        let explained = explain(include_str!("../example.txt")).unwrap();
+        let lines: Vec<&str> = explained.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "line 1: 7 6 4 2 1: safe");
+        assert_eq!(
+            lines[2],
+            "line 3: 9 7 6 2 1: unsafe, levels 2 and 3 differ by more than 3; \
+             no single removal makes it safe"
+        );
+        assert_eq!(
+            lines[3],
+            "line 4: 1 3 2 4 5: unsafe, levels 1 and 2 change direction; \
+             safe without level 1"
+        );
+        assert_eq!(explain("1 2\n\n").unwrap_err().expected, "a level");
+    }
+
+    #[test]
+    fn messages_follow_the_rules() {
+        let rules = Rules {
+            min_step: 2,
+            max_step: 5,
+            allow_equal: true,
+        };
+        let small = first_violation(&[4, 4, 5], &rules).unwrap();
+        assert_eq!(small.index, 1);
+        assert_eq!(small.to_string(), "levels 1 and 2 differ by less than 2");
+        let large = first_violation(&[1, 3, 9], &rules).unwrap();
+        assert_eq!(large.to_string(), "levels 1 and 2 differ by more than 5");
+        assert_eq!(ReportAnalysis::new(&[1, 3, 9, 5], &rules).removal, Some(2));
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_the_dampener_under_any_rules(
+            levels in prop::collection::vec(0..12i32, 1..8),
+            min_step in 0..3u32,
+            max_step in 0..5u32,
+            allow_equal in any::<bool>(),
+        ) {
+            let rules = Rules { min_step, max_step, allow_equal };
+            let analysis = ReportAnalysis::new(&levels, &rules);
+            prop_assert_eq!(analysis.is_safe(), rules.is_safe(&levels, 0));
+            prop_assert_eq!(analysis.is_safe_with_dampener(), rules.is_safe(&levels, 1));
+            if !analysis.is_safe() {
+                let first = (0..levels.len())
+                    .find(|&i| rules.is_safe(&[&levels[..i], &levels[i + 1..]].concat(), 0));
+                prop_assert_eq!(analysis.removal, first);
+            }
+        }
+
+        #[test]
+        fn agrees_with_both_parts(levels in prop::collection::vec(0..12i32, 1..8)) {
+            let analysis = puzzle(&levels);
+            let steps: Vec<i32> = levels.windows(2).map(|w| w[1] - w[0]).collect();
+            let safe = steps.iter().all(|step| (1..=3).contains(step))
+                || steps.iter().all(|step| (-3..=-1).contains(step));
+            prop_assert_eq!(analysis.is_safe(), safe);
+            prop_assert_eq!(analysis.is_safe_with_dampener(), crate::is_valid_part2(&levels));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! The Problem Dampener in a single pass over each report, for any number of
+//! removals and any rules about the steps between levels.
+
+/// Cells of the table in [`Rules::is_safe_going`] kept on the stack, enough
+/// for up to 2 removals; more removals need a heap allocation.
+const INLINE_CELLS: usize = 12;
+
+/// What makes two adjacent levels of a report acceptable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Smallest difference allowed between adjacent levels that differ
+    pub min_step: u32,
+    /// Largest difference allowed between adjacent levels
+    pub max_step: u32,
+    /// Whether adjacent levels may be equal, whatever `min_step` says
+    pub allow_equal: bool,
+}
+
+impl Rules {
+    /// Levels all increasing or all decreasing, by 1 to 3 at a time.
+    pub const PUZZLE: Rules = Rules {
+        min_step: 1,
+        max_step: 3,
+        allow_equal: false,
+    };
+
+    pub(crate) fn allows(&self, from: i32, to: i32, increasing: bool) -> bool {
+        let step = to as i64 - from as i64;
+        if step == 0 {
+            return self.allow_equal;
+        }
+        (step > 0) == increasing
+            && (self.min_step as i64..=self.max_step as i64).contains(&step.abs())
+    }
+
+    /// Whether `levels` follows the rules once at most `removals` levels are
+    /// taken out. Takes O(n·k²) for `n` levels and `k` removals, so a single
+    /// removal is linear.
+    pub fn is_safe(&self, levels: &[i32], removals: usize) -> bool {
+        [true, false]
+            .into_iter()
+            .any(|increasing| self.is_safe_going(levels, removals, increasing))
+    }
+
+    fn is_safe_going(&self, levels: &[i32], removals: usize, increasing: bool) -> bool {
+        let n = levels.len();
+        if n <= removals + 1 {
+            return true;
+        }
+        // `kept[i][r]`: level `i` can be the last one kept so far, with `r`
+        // levels removed before it. The previous level kept is at most
+        // `removals + 1` behind, so the rows are reused in a ring of that
+        // many plus the current one.
+        let width = removals + 1;
+        let rows = removals + 2;
+        let mut inline = [false; INLINE_CELLS];
+        let mut heap = Vec::new();
+        let kept: &mut [bool] = if rows * width <= INLINE_CELLS {
+            &mut inline[..rows * width]
+        } else {
+            heap.resize(rows * width, false);
+            &mut heap
+        };
+        for i in 0..n {
+            let row = i % rows * width;
+            for r in 0..width {
+                kept[row + r] = r == i
+                    || i > 0
+                        && (0..=r.min(i - 1)).any(|skipped| {
+                            let j = i - 1 - skipped;
+                            kept[j % rows * width + r - skipped]
+                                && self.allows(levels[j], levels[i], increasing)
+                        });
+            }
+            // every level after `i` can still be removed
+            let after = n - 1 - i;
+            if after <= removals && kept[row..row + width - after].contains(&true) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use common::Solution;
+    use proptest::prelude::*;
+
+    /// Tries every way of removing up to `removals` levels.
+    fn brute_force(rules: &Rules, levels: &[i32], removals: usize) -> bool {
+        let follows = [true, false].into_iter().any(|increasing| {
+            levels
+                .windows(2)
+                .all(|pair| rules.allows(pair[0], pair[1], increasing))
+        });
+        follows
+            || removals > 0
+                && (0..levels.len()).any(|i| {
+                    let rest = [&levels[..i], &levels[i + 1..]].concat();
+                    brute_force(rules, &rest, removals - 1)
+                })
+    }
+
+    #[test]
+    fn solves_the_example_reports() {
+        let safe: Vec<bool> = Day02::parse(include_str!("../example.txt"))
+            .unwrap()
+            .iter()
+            .map(|levels| Rules::PUZZLE.is_safe(levels, 1))
+            .collect();
+        assert_eq!(safe, [true, false, false, true, true, true]);
+    }
+
+    #[test]
+    fn removals_anywhere_in_the_report() {
+        let rules = Rules::PUZZLE;
+        assert!(rules.is_safe(&[9, 1, 2, 3], 1));
+        assert!(rules.is_safe(&[1, 2, 3, 9], 1));
+        assert!(!rules.is_safe(&[1, 9, 2, 9, 3], 1));
+        assert!(rules.is_safe(&[1, 9, 2, 9, 3], 2));
+        assert!(rules.is_safe(&[5, 5, 5], 2));
+        assert!(!rules.is_safe(&[5, 5, 5], 1));
+        assert!(rules.is_safe(&[], 0));
+    }
+
+    #[test]
+    fn step_rules_are_configurable() {
+        let levels = [1, 1, 4, 9];
+        assert!(!Rules::PUZZLE.is_safe(&levels, 0));
+        let rules = Rules {
+            min_step: 1,
+            max_step: 5,
+            allow_equal: true,
+        };
+        assert!(rules.is_safe(&levels, 0));
+        let rules = Rules {
+            min_step: 2,
+            max_step: 5,
+            allow_equal: false,
+        };
+        assert!(!rules.is_safe(&[1, 3, 4, 6], 0));
+        assert!(rules.is_safe(&[1, 3, 4, 6], 1));
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_brute_force(
+            levels in prop::collection::vec(0..15i32, 0..9),
+            removals in 0..4usize,
+            min_step in 0..3u32,
+            max_step in 0..5u32,
+            allow_equal in any::<bool>(),
+        ) {
+            let rules = Rules { min_step, max_step, allow_equal };
+            prop_assert_eq!(
+                rules.is_safe(&levels, removals),
+                brute_force(&rules, &levels, removals)
+            );
+        }
+    }
+}
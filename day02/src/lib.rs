@@ -0,0 +1,123 @@
+pub mod analysis;
+pub mod dampener;
+
+use analysis::first_violation;
+use common::{Answer, ParseError, Solution, SolveError, Source};
+use dampener::Rules;
+
+fn is_valid(nums: &[i32]) -> bool {
+    first_violation(nums, &Rules::PUZZLE).is_none()
+}
+
+fn is_valid_part2(nums: &[i32]) -> bool {
+    Rules::PUZZLE.is_safe(nums, 1)
+}
+
+fn parse_reports(source: Source) -> Result<Vec<Vec<i32>>, ParseError> {
+    source
+        .lines()
+        .map(|line| {
+            let levels: Vec<i32> = source.ints(line, "a level")?;
+            if levels.is_empty() {
+                return Err(source.error_after(line, "a level"));
+            }
+            Ok(levels)
+        })
+        .collect()
+}
+
+fn part1(reports: &[Vec<i32>]) -> usize {
+    reports.iter().filter(|nums| is_valid(nums)).count()
+}
+
+fn part2(reports: &[Vec<i32>]) -> usize {
+    reports.iter().filter(|nums| is_valid_part2(nums)).count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(Source::new(Self::DAY, input))
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(reports).into())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(reports).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day02 as Solution>::Input {
+        Day02::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 4);
+    }
+
+    #[test]
+    fn is_valid_requires_a_monotonic_gentle_slope() {
+        assert!(is_valid(&[7, 6, 4, 2, 1]));
+        assert!(!is_valid(&[1, 2, 7, 8, 9]));
+        assert!(!is_valid(&[1, 3, 2, 4, 5]));
+        assert!(!is_valid(&[8, 6, 4, 4, 1]));
+    }
+
+    #[test]
+    fn is_valid_part2_tolerates_one_bad_level() {
+        assert!(is_valid_part2(&[1, 3, 2, 4, 5]));
+        assert!(is_valid_part2(&[8, 6, 4, 4, 1]));
+        assert!(!is_valid_part2(&[9, 7, 6, 2, 1]));
+        // removing the first or last level can also fix a report
+        assert!(is_valid_part2(&[9, 1, 2, 3]));
+        assert!(is_valid_part2(&[1, 2, 3, 9]));
+    }
+
+    #[test]
+    fn parse_rejects_text_between_levels() {
+        let err = Day02::parse("7 6 4 2 1\n7 6 x 4 2 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 5, "`x`"));
+        let err = Day02::parse("7 6 4-2 1\n").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (5, "a level"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            reports in prop::collection::vec(prop::collection::vec(0..100i32, 1..10), 1..20),
+        ) {
+            let text: String = reports
+                .iter()
+                .map(|levels| {
+                    let levels: Vec<String> = levels.iter().map(|l| l.to_string()).collect();
+                    levels.join(" ") + "\n"
+                })
+                .collect();
+            prop_assert_eq!(Day02::parse(&text).unwrap(), reports);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9 \n-]{0,40}") {
+            let _ = Day02::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use common::{Answer, Overflow, ParseError, Solution, SolveError, Source};
+use regex::Regex;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+fn parse_instructions(source: Source) -> Result<Vec<Instruction>, ParseError> {
+    let regex = Regex::new(r"mul\((\d+),\s*(\d+)\)|do\(\)|don't\(\)").unwrap();
+    regex
+        .captures_iter(source.text())
+        .map(|cap| match cap.get(0).unwrap().as_str() {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            _ => {
+                let a = source.parse::<i32>(cap.get(1).unwrap().as_str(), "a factor")?;
+                let b = source.parse::<i32>(cap.get(2).unwrap().as_str(), "a factor")?;
+                Ok(Instruction::Mul(a, b))
+            }
+        })
+        .collect()
+}
+
+fn part1(instructions: &[Instruction]) -> Result<i32, Overflow> {
+    instructions
+        .iter()
+        .try_fold(0i32, |acc, instruction| match instruction {
+            Instruction::Mul(a, b) => a.checked_mul(*b)?.checked_add(acc),
+            _ => Some(acc),
+        })
+        .ok_or(Overflow::of::<i32>(Day03::DAY, 1))
+}
+
+fn part2(instructions: &[Instruction]) -> Result<i32, Overflow> {
+    let overflow = Overflow::of::<i32>(Day03::DAY, 2);
+    let mut disabled = false;
+    let mut sum: i32 = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => disabled = false,
+            Instruction::Dont => disabled = true,
+            Instruction::Mul(a, b) if !disabled => {
+                sum = a
+                    .checked_mul(*b)
+                    .and_then(|product| sum.checked_add(product))
+                    .ok_or(overflow)?;
+            }
+            Instruction::Mul(..) => {}
+        }
+    }
+    Ok(sum)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(Source::new(Self::DAY, input))
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(instructions)?.into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(instructions)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day03 as Solution>::Input {
+        Day03::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), Ok(161));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), Ok(161));
+    }
+
+    #[test]
+    fn part2_honours_do_and_dont() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(part2(&Day03::parse(input).unwrap()), Ok(48));
+    }
+
+    #[test]
+    fn sums_beyond_i32_are_errors() {
+        let instructions = Day03::parse("mul(65536,32768)").unwrap();
+        assert_eq!(part1(&instructions), Err(Overflow::of::<i32>(3, 1)));
+        let instructions = Day03::parse("mul(46340,46340)mul(46340,46340)").unwrap();
+        assert_eq!(part1(&instructions), Err(Overflow::of::<i32>(3, 1)));
+        let instructions = Day03::parse("mul(46340,46340)don't()mul(46340,46340)").unwrap();
+        assert_eq!(part1(&instructions), Err(Overflow::of::<i32>(3, 1)));
+        assert_eq!(part2(&instructions), Ok(46340 * 46340));
+        assert_eq!(
+            Day03::part2(&Day03::parse("mul(65536,32768)").unwrap())
+                .unwrap_err()
+                .to_string(),
+            "day 03 part 2 does not fit in i32"
+        );
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            (0..1000i32, 0..1000i32).prop_map(|(a, b)| Instruction::Mul(a, b)),
+            Just(Instruction::Do),
+            Just(Instruction::Dont),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            program in prop::collection::vec((instruction(), "[a-z%&!@ ]{0,5}"), 0..30),
+        ) {
+            // the filler has no parentheses, so it cannot form instructions
+            let text: String = program
+                .iter()
+                .map(|(instruction, filler)| match instruction {
+                    Instruction::Mul(a, b) => format!("mul({},{}){}", a, b, filler),
+                    Instruction::Do => format!("do(){}", filler),
+                    Instruction::Dont => format!("don't(){}", filler),
+                })
+                .collect();
+            let expected: Vec<Instruction> = program.into_iter().map(|(i, _)| i).collect();
+            prop_assert_eq!(Day03::parse(&text).unwrap(), expected);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "(mul\\(|do|n't|\\(|\\)|,|[0-9]{1,12}| )*") {
+            let _ = Day03::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use common::grid::Position;
+use common::{Answer, Direction8, Grid, ParseError, Solution, SolveError, Source};
+
+fn part1(grid: &Grid<char>) -> i32 {
+    let mut count = 0;
+
+    for pos in grid.positions() {
+        for dir in Direction8::ALL {
+            let word = grid.ray(pos, dir.delta()).take(4).map(|p| grid[p]);
+            if word.eq("XMAS".chars()) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn check_mas(pos: Position, grid: &Grid<char>) -> bool {
+    let corner = |dir: Direction8| grid.offset(pos, dir.delta()).map(|p| grid[p]);
+    let is_mas = |a, b| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+
+    // north-east and south-west should be like: MS or SM
+    // similarly: north-west and south-east should be like: MS or SM
+    is_mas(corner(Direction8::NorthEast), corner(Direction8::SouthWest))
+        && is_mas(corner(Direction8::NorthWest), corner(Direction8::SouthEast))
+}
+
+fn part2(grid: &Grid<char>) -> i32 {
+    grid.positions_of(&'A')
+        .filter(|&pos| check_mas(pos, grid))
+        .count() as i32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(Source::new(Self::DAY, input))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::strategy::grid_rows;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day04 as Solution>::Input {
+        Day04::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 3);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rows in grid_rows("[XMAS]")) {
+            let grid = Day04::parse(&rows.join("\n")).unwrap();
+            prop_assert_eq!((grid.width(), grid.height()), (rows[0].len(), rows.len()));
+            for (pos, &c) in grid.iter() {
+                prop_assert_eq!(c, rows[pos.y].as_bytes()[pos.x] as char);
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[XMAS\n]{0,40}") {
+            let _ = Day04::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use common::{Answer, ParseError, Solution, SolveError, Source};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+pub type Rules = HashMap<u32, HashSet<u32>>;
+
+fn parse_pages(source: Source, line: &str) -> Result<Vec<u32>, ParseError> {
+    line.split(',')
+        .map(|page| source.parse(page, "a page number"))
+        .collect()
+}
+
+fn read_input(source: Source) -> Result<(Rules, Vec<Vec<u32>>), ParseError> {
+    let sections = source.sections();
+    let [rule_lines, update_lines] = sections[..] else {
+        return Err(source.error_at_end("rules and updates separated by a blank line"));
+    };
+
+    let mut rules: Rules = HashMap::new();
+    for line in rule_lines.lines() {
+        let (before, after) = source.split_once(line, "|")?;
+        let before = source.parse(before, "a page number")?;
+        let after = source.parse(after, "a page number")?;
+        rules.entry(before).or_default();
+        rules.entry(after).or_default();
+        rules.get_mut(&before).unwrap().insert(after);
+    }
+
+    let updates: Vec<Vec<u32>> = update_lines
+        .lines()
+        .map(|update_line| parse_pages(source, update_line))
+        .collect::<Result<_, _>>()?;
+    Ok((rules, updates))
+}
+
+fn is_valid(rules: &Rules, update: &[u32]) -> bool {
+    let positions: HashMap<u32, usize> = update.iter().enumerate().map(|(i, &u)| (u, i)).collect();
+    for (before, after_set) in rules {
+        if let Some(&before_index) = positions.get(before) {
+            for after in after_set {
+                if let Some(&after_index) = positions.get(after) {
+                    if after_index < before_index {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+    true
+}
+
+fn part1((rules, updates): &(Rules, Vec<Vec<u32>>)) -> u32 {
+    let mut sum = 0;
+    for update in updates {
+        if is_valid(rules, update) {
+            sum += update[update.len() / 2];
+        }
+    }
+    sum
+}
+
+fn part2((rules, updates): &(Rules, Vec<Vec<u32>>)) -> u32 {
+    let mut precedence: HashMap<(u32, u32), Ordering> = HashMap::new();
+    for (&before, after) in rules {
+        for &a in after {
+            precedence.insert((before, a), Ordering::Greater);
+            precedence.insert((a, before), Ordering::Less);
+        }
+    }
+    updates
+        .iter()
+        .filter_map(|update| -> Option<u32> {
+            if !is_valid(rules, update) {
+                let mut update = update.clone();
+                update.sort_by(|&a, &b| *precedence.get(&(a, b)).unwrap_or(&Ordering::Equal));
+                Some(update[update.len() / 2])
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Rules, Vec<Vec<u32>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(Source::new(Self::DAY, input))
+    }
+
+    fn part1(manual: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(manual).into())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(manual).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day05 as Solution>::Input {
+        Day05::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 123);
+    }
+
+    #[test]
+    fn parse_rejects_missing_sections_and_bad_rules() {
+        let err = Day05::parse("47|53\n97|13\n").unwrap_err();
+        assert_eq!(err.expected, "rules and updates separated by a blank line");
+        let err = Day05::parse("47|53\n97-13\n\n75,47\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 6, "`|`"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            rule_list in prop::collection::vec((10..100u32, 10..100u32), 1..30),
+            updates in prop::collection::vec(prop::collection::vec(10..100u32, 1..10), 1..10),
+        ) {
+            let mut text: String = rule_list
+                .iter()
+                .map(|(a, b)| format!("{}|{}\n", a, b))
+                .collect();
+            text.push('\n');
+            for update in &updates {
+                let pages: Vec<String> = update.iter().map(|p| p.to_string()).collect();
+                text += &(pages.join(",") + "\n");
+            }
+
+            let mut expected: Rules = HashMap::new();
+            for &(before, after) in &rule_list {
+                expected.entry(after).or_default();
+                expected.entry(before).or_default().insert(after);
+            }
+            prop_assert_eq!(Day05::parse(&text).unwrap(), (expected, updates));
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9|,\n]{0,40}") {
+            let _ = Day05::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+mod reference;
+
+use common::grid::Position;
+use common::{Answer, Direction4, Grid, ParseError, Point, Solution, SolveError, Source};
+use std::collections::HashSet;
+
+fn find_next_block(
+    current: Position,
+    blocks_positions: &[Position],
+    direction: Direction4,
+) -> Option<Position> {
+    match direction {
+        Direction4::Up => blocks_positions
+            .iter()
+            .filter(|block| block.y < current.y && block.x == current.x)
+            .max_by_key(|block| block.y)
+            .map(|&block| Point::new(block.x, block.y + 1)),
+        Direction4::Down => blocks_positions
+            .iter()
+            .filter(|block| block.y > current.y && block.x == current.x)
+            .min_by_key(|block| block.y)
+            .map(|&block| Point::new(block.x, block.y - 1)),
+        Direction4::Left => blocks_positions
+            .iter()
+            .filter(|block| block.x < current.x && block.y == current.y)
+            .max_by_key(|block| block.x)
+            .map(|&block| Point::new(block.x + 1, block.y)),
+        Direction4::Right => blocks_positions
+            .iter()
+            .filter(|block| block.x > current.x && block.y == current.y)
+            .min_by_key(|block| block.x)
+            .map(|&block| Point::new(block.x - 1, block.y)),
+    }
+}
+
+/// The cells walked from `from` to `to` (both included), which must share a
+/// row or a column.
+fn segment(from: Position, to: Position) -> impl Iterator<Item = Position> {
+    let rows = from.y.min(to.y)..=from.y.max(to.y);
+    let cols = from.x.min(to.x)..=from.x.max(to.x);
+    rows.flat_map(move |row| cols.clone().map(move |col| Point::new(col, row)))
+}
+
+/// Where the guard leaves the lab when nothing blocks it anymore.
+fn exit(current: Position, direction: Direction4, map: &Grid<char>) -> Position {
+    match direction {
+        Direction4::Up => Point::new(current.x, 0),
+        Direction4::Down => Point::new(current.x, map.height() - 1),
+        Direction4::Left => Point::new(0, current.y),
+        Direction4::Right => Point::new(map.width() - 1, current.y),
+    }
+}
+
+pub struct Lab {
+    map: Grid<char>,
+    blocks_positions: Vec<Position>,
+    guard: Position,
+}
+
+fn parse_map(source: Source) -> Result<Lab, ParseError> {
+    let map = Grid::parse(source, "`.`, `#` or `^`", |c| {
+        ".#^".contains(c).then_some(c)
+    })?;
+    let guard = map
+        .find(&'^')
+        .ok_or_else(|| source.error_at_end("a guard `^`"))?;
+    let blocks_positions = map.positions_of(&'#').collect();
+    Ok(Lab {
+        map,
+        blocks_positions,
+        guard,
+    })
+}
+
+fn part1(lab: &Lab) -> i32 {
+    let mut current = lab.guard;
+    let mut direction = Direction4::Up;
+    let mut visited: HashSet<Position> = HashSet::new();
+
+    while let Some(next) = find_next_block(current, &lab.blocks_positions, direction) {
+        visited.extend(segment(current, next));
+        direction = direction.turn_right();
+        current = next;
+    }
+    visited.extend(segment(current, exit(current, direction, &lab.map)));
+    visited.len() as i32
+}
+
+fn loop_detected(start: Position, blocks: &[Position]) -> bool {
+    // the guard is in a loop once it stops at the same place facing the same
+    // way twice
+    let mut visited: HashSet<(Position, Direction4)> = HashSet::new();
+    let mut current = start;
+    let mut direction = Direction4::Up;
+
+    while let Some(next) = find_next_block(current, blocks, direction) {
+        direction = direction.turn_right();
+        current = next;
+        if !visited.insert((current, direction)) {
+            return true;
+        }
+    }
+    false
+}
+
+fn part2(lab: &Lab) -> i32 {
+    let mut loops = 0;
+    for pos in lab.map.positions_of(&'.') {
+        let mut copy_blocks = lab.blocks_positions.clone();
+        copy_blocks.push(pos);
+        if loop_detected(lab.guard, &copy_blocks) {
+            loops += 1;
+        }
+    }
+    loops
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(Source::new(Self::DAY, input))
+    }
+
+    fn part1(lab: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(lab).into())
+    }
+
+    fn part2(lab: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(lab).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::strategy::grid_rows;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day06 as Solution>::Input {
+        Day06::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 6);
+    }
+
+    // The walk as it was before it only followed the guard's turns: it keeps
+    // every cell walked, and for loops the direction it was walked in.
+    fn every_cell_part1(lab: &Lab) -> i32 {
+        let (map, blocks_positions) = (&lab.map, &lab.blocks_positions);
+        let mut current = lab.guard;
+
+        let mut direction = Direction4::Up;
+        let mut visited: HashSet<Position> = HashSet::new();
+
+        visited.insert(current);
+
+        loop {
+            if let Some(next) = find_next_block(current, blocks_positions, direction) {
+                let (row, col) = (current.y, current.x);
+                match direction {
+                    Direction4::Up => (next.y..row).rev().for_each(|r| {
+                        visited.insert(Point::new(col, r));
+                    }),
+                    Direction4::Down => (row..=next.y).for_each(|r| {
+                        visited.insert(Point::new(col, r));
+                    }),
+                    Direction4::Left => (next.x..col).rev().for_each(|c| {
+                        visited.insert(Point::new(c, row));
+                    }),
+                    Direction4::Right => (col..=next.x).for_each(|c| {
+                        visited.insert(Point::new(c, row));
+                    }),
+                }
+
+                direction = direction.turn_right();
+                current = next;
+            } else {
+                let (row, col) = (current.y, current.x);
+                let range: Vec<_> = match direction {
+                    Direction4::Up => (0..row).rev().map(|r| Point::new(col, r)).collect(),
+                    Direction4::Down => (row..map.height()).map(|r| Point::new(col, r)).collect(),
+                    Direction4::Left => (0..col).rev().map(|c| Point::new(c, row)).collect(),
+                    Direction4::Right => (col..map.width()).map(|c| Point::new(c, row)).collect(),
+                };
+
+                visited.extend(range);
+                break;
+            }
+        }
+        visited.len() as i32
+    }
+
+    fn every_cell_loop_detected(
+        mut current: Position,
+        blocks: &[Position],
+        size: Position,
+    ) -> bool {
+        let mut visited: HashSet<(Position, Direction4)> = HashSet::new();
+        let mut direction = Direction4::Up;
+
+        visited.insert((current, direction));
+        loop {
+            let (row, col) = (current.y, current.x);
+            if let Some(next) = find_next_block(current, blocks, direction) {
+                match direction {
+                    Direction4::Up => (next.y..row).rev().for_each(|r| {
+                        visited.insert((Point::new(col, r), direction));
+                    }),
+                    Direction4::Down => (row..=next.y).for_each(|r| {
+                        visited.insert((Point::new(col, r), direction));
+                    }),
+                    Direction4::Left => (next.x..col).rev().for_each(|c| {
+                        visited.insert((Point::new(c, row), direction));
+                    }),
+                    Direction4::Right => (col..=next.x).for_each(|c| {
+                        visited.insert((Point::new(c, row), direction));
+                    }),
+                }
+                direction = direction.turn_right();
+                current = next;
+            } else {
+                let range: Vec<_> = match direction {
+                    Direction4::Up => (0..row)
+                        .rev()
+                        .map(|r| (Point::new(col, r), direction))
+                        .collect(),
+                    Direction4::Down => (row..size.y)
+                        .map(|r| (Point::new(col, r), direction))
+                        .collect(),
+                    Direction4::Left => (0..col)
+                        .rev()
+                        .map(|c| (Point::new(c, row), direction))
+                        .collect(),
+                    Direction4::Right => (col..size.x)
+                        .map(|c| (Point::new(c, row), direction))
+                        .collect(),
+                };
+
+                visited.extend(range);
+                break;
+            }
+            if visited.contains(&(current, direction)) {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn turns_only_walk_agrees_with_the_every_cell_walk() {
+        let lab = example();
+        assert_eq!(part1(&lab), every_cell_part1(&lab));
+        let size = Point::new(lab.map.width(), lab.map.height());
+        let mut looping = 0;
+        for pos in lab.map.positions_of(&'.') {
+            let mut blocks = lab.blocks_positions.clone();
+            blocks.push(pos);
+            let looped = loop_detected(lab.guard, &blocks);
+            assert_eq!(looped, every_cell_loop_detected(lab.guard, &blocks, size));
+            looping += looped as usize;
+        }
+        // both kinds of walks were compared
+        assert!(looping > 0 && looping < lab.map.positions_of(&'.').count());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(mut rows in grid_rows("[.#]"), x in 0..20usize, y in 0..20usize) {
+            let guard = Point::new(x % rows[0].len(), y % rows.len());
+            rows[guard.y].replace_range(guard.x..guard.x + 1, "^");
+            let lab = Day06::parse(&rows.join("\n")).unwrap();
+            prop_assert_eq!(lab.guard, guard);
+            let blocks: Vec<Position> = lab.map.positions_of(&'#').collect();
+            prop_assert_eq!(&lab.blocks_positions, &blocks);
+            for (pos, &c) in lab.map.iter() {
+                prop_assert_eq!(c, rows[pos.y].as_bytes()[pos.x] as char);
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[.#^x\n]{0,40}") {
+            let _ = Day06::parse(&text);
+        }
+    }
+}
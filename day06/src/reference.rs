@@ -0,0 +1,61 @@
+use crate::{Day06, Lab};
+use common::grid::Position;
+use common::{Answer, Direction4, Reference};
+use std::collections::HashSet;
+
+/// The cells the guard walks through one step at a time, with `extra` as an
+/// additional obstruction, or `None` if the guard never leaves.
+fn walk(lab: &Lab, extra: Option<Position>) -> Option<HashSet<Position>> {
+    let mut pos = lab.guard;
+    let mut direction = Direction4::Up;
+    let mut seen = HashSet::from([(pos, direction)]);
+    while let Some(next) = lab.map.offset(pos, direction.delta()) {
+        if lab.map[next] == '#' || Some(next) == extra {
+            direction = direction.turn_right();
+        } else {
+            pos = next;
+        }
+        if !seen.insert((pos, direction)) {
+            return None;
+        }
+    }
+    Some(seen.into_iter().map(|(pos, _)| pos).collect())
+}
+
+impl Reference for Day06 {
+    fn is_valid(lab: &Lab) -> bool {
+        walk(lab, None).is_some()
+    }
+
+    fn reference_part1(lab: &Lab) -> Answer {
+        (walk(lab, None).unwrap().len() as i32).into()
+    }
+
+    fn reference_part2(lab: &Lab) -> Answer {
+        let loops = lab
+            .map
+            .positions_of(&'.')
+            .filter(|&pos| walk(lab, Some(pos)).is_none())
+            .count();
+        (loops as i32).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn solves_the_example() {
+        let lab = Day06::parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day06::reference_part1(&lab), Answer::Signed(41));
+        assert_eq!(Day06::reference_part2(&lab), Answer::Signed(6));
+    }
+
+    #[test]
+    fn a_guard_walking_in_circles_is_invalid() {
+        let lab = Day06::parse(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert!(!Day06::is_valid(&lab));
+    }
+}
@@ -0,0 +1,223 @@
+use common::{Answer, Overflow, ParseError, Solution, SolveError, Source};
+use itertools::Itertools;
+
+fn parse_input(source: Source) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    source
+        .lines()
+        .map(|line| {
+            let (val, numbers) = source.split_once(line, ": ")?;
+            let val: i64 = source.parse(val, "a test value")?;
+            let numbers: Vec<i64> = source.ints(numbers, "a number")?;
+            if numbers.is_empty() {
+                return Err(source.error_after(line, "a number"));
+            }
+            // results only grow, so one too large for `i64` is too large
+            // for the test value
+            if let Some(negative) = numbers.iter().position(|&n| n < 0) {
+                let field = line.split_whitespace().nth(negative + 1).unwrap();
+                return Err(source.error(field, "a non-negative number"));
+            }
+            Ok((val, numbers))
+        })
+        .collect()
+}
+
+fn generate_operator_combinations<'a>(
+    length: usize,
+    operators: &'a [&'a str],
+) -> impl Iterator<Item = Vec<&'a str>> + 'a {
+    std::iter::repeat_n(operators, length)
+        .multi_cartesian_product()
+        .map(|vec| vec.into_iter().copied().collect()) // Convert Vec<&&str> to Vec<&str>
+}
+
+fn part1(data: &[(i64, Vec<i64>)]) -> Result<i64, Overflow> {
+    data.iter()
+        .filter(|(val, numbers)| {
+            let mut combinations = generate_operator_combinations(numbers.len() - 1, &["+", "*"]);
+            combinations.any(|ops| {
+                let mut result = numbers[0];
+                for (idx, op) in ops.iter().enumerate() {
+                    if result > *val {
+                        break;
+                    }
+                    let next = match *op {
+                        "+" => result.checked_add(numbers[idx + 1]),
+                        "*" => result.checked_mul(numbers[idx + 1]),
+                        _ => unreachable!(),
+                    };
+                    match next {
+                        Some(next) => result = next,
+                        None => return false,
+                    }
+                }
+                result == *val
+            })
+        })
+        .try_fold(0i64, |total, (val, _)| total.checked_add(*val))
+        .ok_or(Overflow::of::<i64>(Day07::DAY, 1))
+}
+
+/// `None` when the digits of `a` followed by those of `b` are too large for
+/// `i64`.
+fn concatenate(a: i64, b: i64) -> Option<i64> {
+    // counted with integers, `log10` rounds up just below powers of ten
+    let mut shift: i64 = 10;
+    while shift <= b {
+        shift = shift.checked_mul(10)?;
+    }
+    a.checked_mul(shift)?.checked_add(b)
+}
+
+fn part2(data: &[(i64, Vec<i64>)]) -> Result<i64, Overflow> {
+    data.iter()
+        .filter(|(val, numbers)| {
+            let mut combinations =
+                generate_operator_combinations(numbers.len() - 1, &["+", "*", "||"]);
+            combinations.any(|ops| {
+                let mut result = numbers[0];
+                for (idx, op) in ops.iter().enumerate() {
+                    let next = match *op {
+                        "+" => result.checked_add(numbers[idx + 1]),
+                        "*" => result.checked_mul(numbers[idx + 1]),
+                        "||" => concatenate(result, numbers[idx + 1]),
+                        _ => unreachable!(),
+                    };
+                    match next {
+                        Some(next) if next <= *val => result = next,
+                        _ => return false,
+                    }
+                }
+                result == *val
+            })
+        })
+        .try_fold(0i64, |total, (val, _)| total.checked_add(*val))
+        .ok_or(Overflow::of::<i64>(Day07::DAY, 2))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(Source::new(Self::DAY, input))
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(data)?.into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(data)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day07 as Solution>::Input {
+        Day07::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), Ok(3749));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), Ok(11387));
+    }
+
+    #[test]
+    fn concatenate_appends_digits() {
+        assert_eq!(concatenate(12, 345), Some(12345));
+        assert_eq!(concatenate(15, 6), Some(156));
+        assert_eq!(concatenate(1, 10), Some(110));
+        assert_eq!(concatenate(9, 999), Some(9999));
+        assert_eq!(concatenate(7, 0), Some(70));
+    }
+
+    #[test]
+    fn concatenate_counts_digits_of_large_numbers_exactly() {
+        assert_eq!(
+            concatenate(1, 999_999_999_999_999),
+            Some(1_999_999_999_999_999)
+        );
+        assert_eq!(
+            concatenate(1, 1_000_000_000_000_000),
+            Some(11_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn concatenate_too_large_for_i64() {
+        assert_eq!(concatenate(i64::MAX / 10, 9), None);
+        assert_eq!(concatenate(0, 1_000_000_000_000_000_000), None);
+        assert_eq!(concatenate(10, 223_372_036_854_775_807), None);
+        assert_eq!(
+            concatenate(9, 223_372_036_854_775_807),
+            Some(9_223_372_036_854_775_807)
+        );
+    }
+
+    #[test]
+    fn results_too_large_for_i64_do_not_match() {
+        let data = Day07::parse("18: 9 2\n10: 10 9223372036854775807\n").unwrap();
+        assert_eq!(part1(&data), Ok(18));
+        assert_eq!(part2(&data), Ok(18));
+        let data = Day07::parse("9223372036854775807: 9223372036854775807\n7: 7\n").unwrap();
+        assert_eq!(part1(&data), Err(Overflow::of::<i64>(7, 1)));
+        assert_eq!(part2(&data), Err(Overflow::of::<i64>(7, 2)));
+    }
+
+    #[test]
+    fn parse_rejects_negative_numbers() {
+        let err = Day07::parse("5: 10 -5\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str(), err.found.as_str()),
+            (7, "a non-negative number", "`-5`")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_text_between_numbers() {
+        let err = Day07::parse("190: 10 19zz\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str(), err.found.as_str()),
+            (9, "a number", "`19zz`")
+        );
+        let err = Day07::parse("190: 10, 19\n").unwrap_err();
+        assert_eq!(err.found, "`10,`");
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(
+            equations in prop::collection::vec(
+                (0..1_000_000_000_000i64, prop::collection::vec(0..1000i64, 1..8)),
+                1..20,
+            ),
+        ) {
+            let text: String = equations
+                .iter()
+                .map(|(value, numbers)| {
+                    let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+                    format!("{}: {}\n", value, numbers.join(" "))
+                })
+                .collect();
+            prop_assert_eq!(Day07::parse(&text).unwrap(), equations);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9: \n]{0,40}") {
+            let _ = Day07::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use common::{Answer, Grid, ParseError, Point, Solution, SolveError, Source};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub struct Antennas {
+    nodes: HashMap<char, Vec<Point<isize>>>,
+    map: Grid<char>,
+}
+
+fn parse_map(source: Source) -> Result<Antennas, ParseError> {
+    let map = Grid::parse_chars(source)?;
+    Ok(Antennas {
+        nodes: find_node_pairs(&map),
+        map,
+    })
+}
+
+fn find_node_pairs(map: &Grid<char>) -> HashMap<char, Vec<Point<isize>>> {
+    map.iter()
+        .filter(|&(_, &ch)| ch != '.')
+        .fold(HashMap::new(), |mut acc, (pos, &ch)| {
+            acc.entry(ch).or_default().push(pos.map(|v| v as isize));
+            acc
+        })
+}
+
+fn is_in(map: &Grid<char>, point: Point<isize>) -> bool {
+    map.position(point).is_some()
+}
+
+fn get_antinodes(pairs: &[Point<isize>], map: &Grid<char>) -> HashSet<Point<isize>> {
+    pairs
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| {
+            pairs.iter().skip(i + 1).flat_map(move |&b| {
+                let diff = b - a;
+                [a - diff, b + diff]
+            })
+        })
+        .filter(|&p| is_in(map, p))
+        .collect()
+}
+
+fn get_antinodes_p2(pairs: &[Point<isize>], map: &Grid<char>) -> HashSet<Point<isize>> {
+    let mut antinodes = HashSet::new();
+    for i in 0..pairs.len() {
+        for j in i + 1..pairs.len() {
+            let a = pairs[i];
+            let b = pairs[j];
+            let diff = b - a;
+
+            // walk outwards from each antenna, starting on the antenna itself
+            let mut p1 = a;
+            while is_in(map, p1) {
+                antinodes.insert(p1);
+                p1 -= diff;
+            }
+            let mut p2 = b;
+            while is_in(map, p2) {
+                antinodes.insert(p2);
+                p2 += diff;
+            }
+        }
+    }
+    antinodes
+}
+
+fn part1(antennas: &Antennas) -> usize {
+    antennas
+        .nodes
+        .values()
+        .flat_map(|node_points| get_antinodes(node_points, &antennas.map))
+        .unique()
+        .count()
+}
+
+fn part2(antennas: &Antennas) -> usize {
+    antennas
+        .nodes
+        .values()
+        .flat_map(|node_points| get_antinodes_p2(node_points, &antennas.map))
+        .unique()
+        .count()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Antennas;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(Source::new(Self::DAY, input))
+    }
+
+    fn part1(antennas: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(antennas).into())
+    }
+
+    fn part2(antennas: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(antennas).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::strategy::grid_rows;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day08 as Solution>::Input {
+        Day08::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 14);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 34);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rows in grid_rows("[.aA0]")) {
+            let antennas = Day08::parse(&rows.join("\n")).unwrap();
+            let mut expected: HashMap<char, Vec<Point<isize>>> = HashMap::new();
+            for (y, row) in rows.iter().enumerate() {
+                for (x, c) in row.chars().enumerate().filter(|&(_, c)| c != '.') {
+                    expected.entry(c).or_default().push(Point::new(x as isize, y as isize));
+                }
+            }
+            prop_assert_eq!(antennas.nodes, expected);
+            prop_assert_eq!(antennas.map.width(), rows[0].len());
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[.a0\n]{0,40}") {
+            let _ = Day08::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use common::{Answer, ParseError, Solution, SolveError, Source};
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Data {
+    id: usize,
+    free: i32,
+    used: i32,
+}
+
+fn parse_data(source: Source) -> Result<Vec<Data>, ParseError> {
+    let trimmed = source.text().trim();
+    if trimmed.is_empty() {
+        return Err(source.error_at_end("a disk map"));
+    }
+    let digits = trimmed
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|digit| digit as i32)
+                .ok_or_else(|| source.error(&trimmed[i..i + c.len_utf8()], "a digit"))
+        })
+        .collect::<Result<Vec<i32>, _>>()?;
+    Ok(digits
+        .chunks(2)
+        .enumerate()
+        .map(|(id, chunk)| Data {
+            id,
+            used: chunk[0],
+            free: chunk.get(1).copied().unwrap_or(0),
+        })
+        .collect())
+}
+
+fn compute_checksum(disk: &[usize]) -> i64 {
+    disk.iter()
+        .enumerate()
+        .map(|(idx, &id)| id as i64 * idx as i64)
+        .sum()
+}
+
+fn part1(data: &[Data]) -> i64 {
+    let mut data = data.to_vec();
+    // we know the size of the disk will be the sum of all memory used in part 1
+    // so it is more efficient to preallocate the vector
+    let mut disk = Vec::with_capacity(data.iter().map(|d| d.used as usize).sum());
+    for i in 0..data.len() {
+        let initial_allocations = std::iter::repeat_n(data[i].id, data[i].used as usize);
+        disk.extend(initial_allocations);
+        data[i].used = 0;
+        for j in (i..data.len()).rev() {
+            let amount = data[i].free.min(data[j].used);
+            let allocation = std::iter::repeat_n(data[j].id, amount as usize);
+            disk.extend(allocation);
+            data[i].free -= amount;
+            data[j].used -= amount;
+            data[j].free = 0;
+            if data[i].free == 0 {
+                break;
+            }
+        }
+    }
+    compute_checksum(&disk)
+}
+
+fn move_data(data: &mut Vec<Data>, idx: usize, location: usize) {
+    data[location].free -= data[idx].used;
+    if idx != data.len() - 1 {
+        data[idx - 1].free += data[idx].free + data[idx].used;
+    }
+    data[idx].free = data[location].free;
+    data[location].free = 0;
+    let temp = data.remove(idx);
+    data.insert(location + 1, temp);
+}
+
+fn compute_checksum_p2(disk: &[Data]) -> i64 {
+    let mut checksum = 0;
+    let mut multiple = 0;
+    for data in disk {
+        for _ in 0..data.used {
+            checksum += multiple * data.id as i64;
+            multiple += 1;
+        }
+        multiple += data.free as i64;
+    }
+    checksum
+}
+
+fn part2(data: &[Data]) -> i64 {
+    let mut data = data.to_vec();
+    let mut i = data.len() - 1;
+    while i > 0 {
+        let elem = data[i];
+        if elem.used == 0 {
+            continue;
+        }
+        for j in 0..i {
+            if data[j].free >= elem.used {
+                move_data(&mut data, i, j);
+                i += 1; // adjust the index since we changed the vector order
+                break;
+            }
+        }
+        i -= 1;
+    }
+    compute_checksum_p2(&data)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Data>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_data(Source::new(Self::DAY, input))
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(data).into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(data).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day09 as Solution>::Input {
+        Day09::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 2858);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(digits in prop::collection::vec(0..10i32, 1..100)) {
+            let text: String = digits.iter().map(|d| d.to_string()).collect::<String>() + "\n";
+            let data = Day09::parse(&text).unwrap();
+            prop_assert_eq!(data.len(), digits.len().div_ceil(2));
+            for (i, file) in data.iter().enumerate() {
+                prop_assert_eq!(file.id, i);
+                prop_assert_eq!(file.used, digits[2 * i]);
+                prop_assert_eq!(file.free, digits.get(2 * i + 1).copied().unwrap_or(0));
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9 x\n]{0,40}") {
+            let _ = Day09::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use common::grid::Position;
+use common::{Answer, Grid, ParseError, Solution, SolveError, Source};
+
+fn parse_data(source: Source) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(source)
+}
+
+fn get_next_locations(data: &Grid<u32>, pos: Position) -> impl Iterator<Item = Position> + '_ {
+    data.neighbours4(pos)
+        .filter(move |&next| data[pos] + 1 == data[next])
+}
+
+fn part1(grid: &Grid<u32>) -> usize {
+    grid.positions_of(&0)
+        .map(|start| {
+            search::bfs_reach(start, |&pos| get_next_locations(grid, pos))
+                .into_iter()
+                .filter(|&pos| grid[pos] == 9)
+                .count()
+        })
+        .sum()
+}
+
+fn part2(grid: &Grid<u32>) -> usize {
+    // heights strictly increase along a trail, so there are no cycles
+    grid.positions_of(&0)
+        .map(|start| {
+            search::count_paths(
+                start,
+                |&pos| get_next_locations(grid, pos),
+                |&pos| grid[pos] == 9,
+            )
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_data(Source::new(Self::DAY, input))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::strategy::grid_rows;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day10 as Solution>::Input {
+        Day10::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 81);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rows in grid_rows("[0-9]")) {
+            let grid = Day10::parse(&rows.join("\n")).unwrap();
+            prop_assert_eq!((grid.width(), grid.height()), (rows[0].len(), rows.len()));
+            for (pos, &height) in grid.iter() {
+                let digit = rows[pos.y].as_bytes()[pos.x] - b'0';
+                prop_assert_eq!(height, u32::from(digit));
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9.\n]{0,40}") {
+            let _ = Day10::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+mod reference;
+
+use common::{Answer, Overflow, ParseError, Solution, SolveError, Source};
+use std::collections::HashMap;
+
+fn parse_data(source: Source) -> Result<Vec<u64>, ParseError> {
+    source.ints(source.text(), "a stone number")
+}
+
+fn get_digit_count(number: u64) -> u32 {
+    let mut count = 0;
+    let mut num = number;
+    while num > 0 {
+        num /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// The stones `stone` turns into, or `None` when its number grows too large
+/// for `u64`.
+fn transform_stone(stone: u64) -> Option<[Option<u64>; 2]> {
+    if stone == 0 {
+        Some([Some(1), None])
+    } else {
+        let digit_count = get_digit_count(stone);
+        if digit_count.is_multiple_of(2) {
+            let half = digit_count / 2;
+            let divisor = 10u64.pow(half);
+            let left = stone / divisor;
+            let right = stone % divisor;
+
+            Some([Some(left), Some(right)])
+        } else {
+            Some([Some(stone.checked_mul(2024)?), None])
+        }
+    }
+}
+
+fn transform_n_times(stone: u64, n: u8, cache: &mut HashMap<(u64, u8), u64>) -> Option<u64> {
+    if n == 0 {
+        return Some(1);
+    }
+    if let Some(&val) = cache.get(&(stone, n)) {
+        return Some(val);
+    }
+    let mut size: u64 = 0;
+    for t in transform_stone(stone)?.into_iter().flatten() {
+        size = size.checked_add(transform_n_times(t, n - 1, cache)?)?;
+    }
+    cache.insert((stone, n), size);
+    Some(size)
+}
+
+/// Number of stones after `blinks` blinks, or `None` when a stone or the count
+/// does not fit in `u64`.
+fn count_after(stones: &[u64], blinks: u8) -> Option<u64> {
+    let mut cache = HashMap::new();
+    stones.iter().try_fold(0u64, |total, &stone| {
+        total.checked_add(transform_n_times(stone, blinks, &mut cache)?)
+    })
+}
+
+fn part1(stones: &[u64]) -> Result<u64, Overflow> {
+    const BLINK: u8 = 25;
+    count_after(stones, BLINK).ok_or(Overflow::of::<u64>(Day11::DAY, 1))
+}
+
+fn part2(stones: &[u64]) -> Result<u64, Overflow> {
+    const BLINK: u8 = 75;
+    count_after(stones, BLINK).ok_or(Overflow::of::<u64>(Day11::DAY, 2))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_data(Source::new(Self::DAY, input))
+    }
+
+    fn part1(stones: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(stones)?.into())
+    }
+
+    fn part2(stones: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(stones)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day11 as Solution>::Input {
+        Day11::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), Ok(55312));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), Ok(65601038650482));
+    }
+
+    #[test]
+    fn transform_stone_zero_becomes_one() {
+        assert_eq!(transform_stone(0), Some([Some(1), None]));
+    }
+
+    #[test]
+    fn transform_stone_splits_even_digit_counts() {
+        assert_eq!(transform_stone(10), Some([Some(1), Some(0)]));
+        assert_eq!(transform_stone(1000), Some([Some(10), Some(0)]));
+        assert_eq!(transform_stone(253000), Some([Some(253), Some(0)]));
+        assert_eq!(
+            transform_stone(u64::MAX),
+            Some([Some(1844674407), Some(3709551615)])
+        );
+    }
+
+    #[test]
+    fn transform_stone_multiplies_odd_digit_counts() {
+        assert_eq!(transform_stone(1), Some([Some(2024), None]));
+        assert_eq!(transform_stone(125), Some([Some(253000), None]));
+    }
+
+    #[test]
+    fn stones_too_large_for_u64_are_errors() {
+        // the smallest number with an odd digit count that overflows
+        assert_eq!(transform_stone(10u64.pow(16)), None);
+        assert_eq!(
+            transform_stone(10u64.pow(14)),
+            Some([Some(202_400_000_000_000_000), None])
+        );
+        assert_eq!(part1(&[10u64.pow(16)]), Err(Overflow::of::<u64>(11, 1)));
+        assert_eq!(part2(&[10u64.pow(16)]), Err(Overflow::of::<u64>(11, 2)));
+    }
+
+    #[test]
+    fn get_digit_count_of_zero_is_zero() {
+        assert_eq!(get_digit_count(0), 0);
+        assert_eq!(get_digit_count(9), 1);
+        assert_eq!(get_digit_count(10), 2);
+    }
+
+    #[test]
+    fn parse_rejects_text_between_stones() {
+        assert!(Day11::parse("125 17\n").is_ok());
+        let err = Day11::parse("125 x 17").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (5, "`x`"));
+        let err = Day11::parse("125-17\n").unwrap_err();
+        assert_eq!(err.expected, "a stone number");
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(stones in prop::collection::vec(any::<u64>(), 0..20)) {
+            let text = stones.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(" ") + "\n";
+            prop_assert_eq!(Day11::parse(&text).unwrap(), stones);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[0-9 \n-]{0,40}") {
+            let _ = Day11::parse(&text);
+        }
+    }
+}
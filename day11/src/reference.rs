@@ -0,0 +1,67 @@
+use crate::Day11;
+use common::{Answer, Reference};
+use std::collections::HashMap;
+
+/// One blink of one stone, following the rules word for word.
+fn blink(stone: u64) -> Vec<u64> {
+    let digits = stone.to_string();
+    if stone == 0 {
+        vec![1]
+    } else if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        vec![left.parse().unwrap(), right.parse().unwrap()]
+    } else {
+        vec![stone * 2024]
+    }
+}
+
+/// Keeps the whole line of stones.
+fn line_after(stones: &[u64], blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        stones = stones.into_iter().flat_map(blink).collect();
+    }
+    stones.len()
+}
+
+/// Counts the stones of each number; the line itself would not fit in
+/// memory after 75 blinks, and the order of stones never matters.
+fn counted_after(stones: &[u64], blinks: usize) -> u64 {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &stone in stones {
+        *counts.entry(stone).or_default() += 1;
+    }
+    for _ in 0..blinks {
+        let mut next = HashMap::new();
+        for (stone, count) in counts {
+            for new in blink(stone) {
+                *next.entry(new).or_default() += count;
+            }
+        }
+        counts = next;
+    }
+    counts.values().sum()
+}
+
+impl Reference for Day11 {
+    fn reference_part1(stones: &Vec<u64>) -> Answer {
+        line_after(stones, 25).into()
+    }
+
+    fn reference_part2(stones: &Vec<u64>) -> Answer {
+        counted_after(stones, 75).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_the_example() {
+        assert_eq!(line_after(&[125, 17], 6), 22);
+        assert_eq!(counted_after(&[125, 17], 6), 22);
+        assert_eq!(line_after(&[125, 17], 25), 55312);
+        assert_eq!(counted_after(&[125, 17], 25), 55312);
+    }
+}
@@ -0,0 +1,174 @@
+mod reference;
+
+use common::grid::Position;
+use common::{Answer, Grid, ParseError, Point, Solution, SolveError, Source};
+use std::collections::HashMap;
+
+fn get_perimeter_for_cell(grid: &Grid<char>, pos: Position) -> usize {
+    4 - grid
+        .neighbours4(pos)
+        .filter(|&next| grid[next] == grid[pos])
+        .count()
+}
+
+fn get_next_locations(grid: &Grid<char>, pos: Position) -> impl Iterator<Item = Position> + '_ {
+    grid.neighbours4(pos)
+        .filter(move |&next| grid[next] == grid[pos])
+}
+
+fn get_regions(grid: &Grid<char>) -> Vec<Vec<Position>> {
+    search::connected_components(grid.positions(), |&pos| get_next_locations(grid, pos))
+}
+
+fn get_perimeter(grid: &Grid<char>, region: &[Position]) -> usize {
+    region
+        .iter()
+        .map(|&pos| get_perimeter_for_cell(grid, pos))
+        .sum()
+}
+
+fn find_number_of_sides(path: Vec<Position>) -> usize {
+    let mut corners: HashMap<Position, Vec<Position>> = HashMap::new();
+    // a cell at (x, y) has its corners at (x, y), (x + 1, y), (x, y + 1) and (x + 1, y + 1)
+    const CORNER_MOVEMENTS: [Position; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ];
+
+    // Record all corners and the points contributing to them
+    for &cell in path.iter() {
+        for &corner in CORNER_MOVEMENTS.iter() {
+            corners.entry(cell + corner).or_default().push(cell);
+        }
+    }
+
+    let mut sides = 0;
+
+    for (_corner, points) in corners {
+        let count = points.len();
+        if count % 2 == 1 {
+            sides += 1;
+        }
+
+        if count == 2 {
+            // EDGE CASE:
+            // AAAAAA
+            // AAABBA
+            // AAABBA
+            // ABBAAA
+            // ABBAAA
+            // AAAAAA
+
+            // A has 12 sides, fences do not go through the diagonal touching B
+
+            let (p1, p2) = (points[0], points[1]);
+            let is_diagonal = p1.x != p2.x && p1.y != p2.y;
+            if is_diagonal {
+                sides += 2;
+            }
+        }
+    }
+
+    sides
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    get_regions(grid)
+        .iter()
+        .map(|region| region.len() * get_perimeter(grid, region))
+        .sum()
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    get_regions(grid)
+        .into_iter()
+        .map(|region| region.len() * find_number_of_sides(region))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(Source::new(Self::DAY, input))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::strategy::grid_rows;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day12 as Solution>::Input {
+        Day12::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 1930);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), 1206);
+    }
+
+    fn region(grid: &str, plant: char) -> Vec<Position> {
+        grid.lines()
+            .enumerate()
+            .flat_map(|(r, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(move |&(_, c)| c == plant)
+                    .map(move |(c, _)| Point::new(c, r))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn find_number_of_sides_of_simple_shapes() {
+        assert_eq!(find_number_of_sides(region("A", 'A')), 4);
+        assert_eq!(find_number_of_sides(region("AAA", 'A')), 4);
+        assert_eq!(find_number_of_sides(region("A.\nAA", 'A')), 6);
+        assert_eq!(find_number_of_sides(region("EEE\nE.E\nEEE", 'E')), 8);
+    }
+
+    #[test]
+    fn find_number_of_sides_does_not_cross_diagonal_corners() {
+        let grid = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA";
+        assert_eq!(find_number_of_sides(region(grid, 'A')), 12);
+        assert_eq!(part2(&Day12::parse(grid).unwrap()), 368);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rows in grid_rows("[A-D]")) {
+            let grid = Day12::parse(&rows.join("\n")).unwrap();
+            prop_assert_eq!((grid.width(), grid.height()), (rows[0].len(), rows.len()));
+            for (pos, &plant) in grid.iter() {
+                prop_assert_eq!(plant, rows[pos.y].as_bytes()[pos.x] as char);
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(text in "[A-D\n]{0,40}") {
+            let _ = Day12::parse(&text);
+        }
+    }
+}
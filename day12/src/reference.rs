@@ -0,0 +1,93 @@
+use crate::Day12;
+use common::grid::Position;
+use common::{Answer, Direction4, Grid, Reference};
+use std::collections::HashSet;
+
+/// Every region, found by flooding from each cell not seen yet.
+fn regions(garden: &Grid<char>) -> Vec<Vec<Position>> {
+    let mut seen = HashSet::new();
+    let mut regions = Vec::new();
+    for start in garden.positions() {
+        if !seen.insert(start) {
+            continue;
+        }
+        let mut region = Vec::new();
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            region.push(pos);
+            for next in garden.neighbours4(pos) {
+                if garden[next] == garden[pos] && seen.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+/// Whether a fence runs along the `direction` side of `pos`.
+fn fenced(garden: &Grid<char>, pos: Position, direction: Direction4) -> bool {
+    garden
+        .offset(pos, direction.delta())
+        .is_none_or(|next| garden[next] != garden[pos])
+}
+
+fn fences(garden: &Grid<char>, region: &[Position]) -> Vec<(Position, Direction4)> {
+    region
+        .iter()
+        .flat_map(|&pos| Direction4::ALL.map(|direction| (pos, direction)))
+        .filter(|&(pos, direction)| fenced(garden, pos, direction))
+        .collect()
+}
+
+/// Counts the fence pieces that start a side: the piece on the cell beside
+/// them, along the fence, does not exist.
+fn sides(garden: &Grid<char>, region: &[Position]) -> usize {
+    fences(garden, region)
+        .into_iter()
+        .filter(|&(pos, direction)| {
+            let along = direction.turn_left().delta();
+            !garden
+                .offset(pos, along)
+                .is_some_and(|prev| garden[prev] == garden[pos] && fenced(garden, prev, direction))
+        })
+        .count()
+}
+
+impl Reference for Day12 {
+    fn reference_part1(garden: &Grid<char>) -> Answer {
+        regions(garden)
+            .iter()
+            .map(|region| region.len() * fences(garden, region).len())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn reference_part2(garden: &Grid<char>) -> Answer {
+        regions(garden)
+            .iter()
+            .map(|region| region.len() * sides(garden, region))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn solves_the_example() {
+        let garden = Day12::parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day12::reference_part1(&garden), Answer::Unsigned(1930));
+        assert_eq!(Day12::reference_part2(&garden), Answer::Unsigned(1206));
+    }
+
+    #[test]
+    fn diagonal_neighbours_do_not_merge_sides() {
+        let garden = Day12::parse("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n").unwrap();
+        assert_eq!(Day12::reference_part2(&garden), Answer::Unsigned(368));
+    }
+}
@@ -0,0 +1,291 @@
+mod reference;
+
+use common::{Answer, Overflow, ParseError, Solution, SolveError, Source};
+use nalgebra::{Matrix2, Vector2};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Button {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    button_a: Button,
+    button_b: Button,
+    prize: (i64, i64),
+}
+
+fn read_input(source: Source) -> Result<Vec<Game>, ParseError> {
+    source
+        .sections()
+        .into_iter()
+        .map(|section| {
+            let mut lines = section.lines();
+            let mut next = |expected| {
+                lines
+                    .next()
+                    .ok_or_else(|| source.error_after(section, expected))
+            };
+            let game = Game {
+                button_a: parse_button(source, next("a `Button A: ` line")?, "Button A: ")?,
+                button_b: parse_button(source, next("a `Button B: ` line")?, "Button B: ")?,
+                prize: parse_prize(source, next("a `Prize: ` line")?)?,
+            };
+            match lines.next() {
+                Some(extra) => Err(source.error(extra, "a blank line")),
+                None => Ok(game),
+            }
+        })
+        .collect()
+}
+
+/// Parses `X<sign>x, Y<sign>y` after `label`.
+fn parse_coordinates(
+    source: Source,
+    line: &str,
+    label: &str,
+    sign: char,
+) -> Result<(i64, i64), ParseError> {
+    let rest = source.strip_prefix(line, label)?;
+    let (x, y) = source.split_once(rest, ", ")?;
+    let x = source.strip_prefix(x, &format!("X{}", sign))?;
+    let y = source.strip_prefix(y, &format!("Y{}", sign))?;
+    Ok((
+        source.parse(x, "an X coordinate")?,
+        source.parse(y, "a Y coordinate")?,
+    ))
+}
+
+fn parse_button(source: Source, line: &str, label: &str) -> Result<Button, ParseError> {
+    let (x, y) = parse_coordinates(source, line, label, '+')?;
+    Ok(Button { x, y })
+}
+
+fn parse_prize(source: Source, line: &str) -> Result<(i64, i64), ParseError> {
+    parse_coordinates(source, line, "Prize: ", '=')
+}
+
+/// The presses of A and B that win the prize, if any. Checking them can
+/// overflow, which is reported as an error of `part`.
+fn solve_equation(game: &Game, part: u8) -> Result<Option<(i64, i64)>, Overflow> {
+    let matrix = Matrix2::new(
+        game.button_a.x as f64,
+        game.button_b.x as f64,
+        game.button_a.y as f64,
+        game.button_b.y as f64,
+    );
+    let vector = Vector2::new(game.prize.0 as f64, game.prize.1 as f64);
+    let Some(inv) = matrix.try_inverse() else {
+        return Ok(None);
+    };
+    let solution = inv * vector;
+    let r1 = solution[0].round() as i64;
+    let r2 = solution[1].round() as i64;
+
+    // buttons cannot be pressed a negative number of times
+    if r1 < 0 || r2 < 0 {
+        return Ok(None);
+    }
+    let reconstruct = |a: i64, b: i64| a.checked_mul(r1)?.checked_add(b.checked_mul(r2)?);
+    let overflow = Overflow::of::<i64>(Day13::DAY, part);
+    let reconstructed_prize_x = reconstruct(game.button_a.x, game.button_b.x).ok_or(overflow)?;
+    let reconstructed_prize_y = reconstruct(game.button_a.y, game.button_b.y).ok_or(overflow)?;
+
+    Ok(
+        (reconstructed_prize_x == game.prize.0 && reconstructed_prize_y == game.prize.1)
+            .then_some((r1, r2)),
+    )
+}
+
+fn get_price(games: &[Game], part: u8) -> Result<i64, Overflow> {
+    let overflow = Overflow::of::<i64>(Day13::DAY, part);
+    games.iter().try_fold(0, |total: i64, g| {
+        let Some((a, b)) = solve_equation(g, part)? else {
+            return Ok(total);
+        };
+        a.checked_mul(A_PRICE)
+            .and_then(|price| price.checked_add(b.checked_mul(B_PRICE)?))
+            .and_then(|price| total.checked_add(price))
+            .ok_or(overflow)
+    })
+}
+
+const A_PRICE: i64 = 3;
+const B_PRICE: i64 = 1;
+
+fn part1(games: &[Game]) -> Result<i64, Overflow> {
+    get_price(games, 1)
+}
+
+const AMOUNT_TO_ADD: i64 = 10000000000000;
+fn part2(games: &[Game]) -> Result<i64, Overflow> {
+    let mut games = games.to_vec();
+    for g in &mut games {
+        g.prize = g
+            .prize
+            .0
+            .checked_add(AMOUNT_TO_ADD)
+            .zip(g.prize.1.checked_add(AMOUNT_TO_ADD))
+            .ok_or(Overflow::of::<i64>(Day13::DAY, 2))?;
+    }
+    get_price(&games, 2)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(Source::new(Self::DAY, input))
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(games)?.into())
+    }
+
+    fn part2(games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(games)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day13 as Solution>::Input {
+        Day13::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), Ok(480));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&example()), Ok(875318608908));
+    }
+
+    fn game(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Game {
+        Game {
+            button_a: Button { x: a.0, y: a.1 },
+            button_b: Button { x: b.0, y: b.1 },
+            prize,
+        }
+    }
+
+    #[test]
+    fn solve_equation_finds_integer_presses() {
+        let g = game((94, 34), (22, 67), (8400, 5400));
+        assert_eq!(solve_equation(&g, 1), Ok(Some((80, 40))));
+    }
+
+    #[test]
+    fn solve_equation_rejects_fractional_presses() {
+        let g = game((26, 66), (67, 21), (12748, 12176));
+        assert_eq!(solve_equation(&g, 1), Ok(None));
+    }
+
+    #[test]
+    fn solve_equation_rejects_parallel_buttons() {
+        let g = game((1, 1), (2, 2), (3, 3));
+        assert_eq!(solve_equation(&g, 1), Ok(None));
+    }
+
+    #[test]
+    fn solve_equation_rejects_negative_presses() {
+        let g = game((20, 42), (34, 97), (10000000001718, 10000000003787));
+        assert_eq!(solve_equation(&g, 1), Ok(None));
+    }
+
+    #[test]
+    fn solve_equation_handles_large_prizes() {
+        let g = game((26, 66), (67, 21), (10000000012748, 10000000012176));
+        assert_eq!(
+            solve_equation(&g, 1),
+            Ok(Some((118679050709, 103199174542)))
+        );
+    }
+
+    #[test]
+    fn costs_too_large_for_i64_are_errors() {
+        let g = game((1, 0), (0, 1), (1 << 62, 0));
+        assert_eq!(solve_equation(&g, 1), Ok(Some((1 << 62, 0))));
+        assert_eq!(part1(&[g]), Err(Overflow::of::<i64>(13, 1)));
+        let g = game((1, 0), (0, 1), (i64::MAX, 0));
+        assert_eq!(part2(&[g]), Err(Overflow::of::<i64>(13, 2)));
+        // `i64::MAX` is rounded up to 2^63 in `f64`, so the guess of 2^62
+        // presses cannot be checked in `i64`
+        let g = game((2, 0), (0, 1), (i64::MAX, 0));
+        assert_eq!(solve_equation(&g, 1), Err(Overflow::of::<i64>(13, 1)));
+    }
+
+    #[test]
+    fn parse_rejects_incomplete_games() {
+        let text = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+                    Button A: X+1, Y+2\n";
+        let err = Day13::parse(text).unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (5, "a `Button B: ` line")
+        );
+        let err = Day13::parse("Button A: X+94\n").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (15, "`, `"));
+    }
+
+    #[test]
+    fn parse_rejects_text_between_numbers() {
+        let err = Day13::parse("Button A: X+94, Y+34zz\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str(), err.found.as_str()),
+            (19, "a Y coordinate", "`34zz`")
+        );
+        let err = Day13::parse("Button A: X+94, and Y+34\n").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (17, "`Y+`"));
+    }
+
+    fn offset() -> impl Strategy<Value = Button> {
+        (1..100i64, 1..100i64).prop_map(|(x, y)| Button { x, y })
+    }
+
+    fn games() -> impl Strategy<Value = Vec<Game>> {
+        let game = (offset(), offset(), (0..20_000i64, 0..20_000i64)).prop_map(
+            |(button_a, button_b, prize)| Game {
+                button_a,
+                button_b,
+                prize,
+            },
+        );
+        prop::collection::vec(game, 1..10)
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(games in games()) {
+            let text = games
+                .iter()
+                .map(|g| {
+                    format!(
+                        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                        g.button_a.x, g.button_a.y, g.button_b.x, g.button_b.y, g.prize.0, g.prize.1
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            prop_assert_eq!(Day13::parse(&text).unwrap(), games);
+        }
+
+        #[test]
+        fn parse_never_panics(
+            text in "(Button A: |Button B: |Prize: |X[+=]|Y[+=]|, |[0-9]{1,3}|\n)*",
+        ) {
+            let _ = Day13::parse(&text);
+        }
+    }
+}
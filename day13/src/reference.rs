@@ -0,0 +1,79 @@
+use crate::{Day13, Game, AMOUNT_TO_ADD, A_PRICE, B_PRICE};
+use common::{Answer, Reference};
+
+/// Tries every number of presses of button A.
+fn cheapest_by_search(game: &Game) -> Option<i64> {
+    let (a, b, prize) = (&game.button_a, &game.button_b, game.prize);
+    (0..=prize.0 / a.x)
+        .filter_map(|presses_a| {
+            let rest = prize.0 - presses_a * a.x;
+            let presses_b = rest / b.x;
+            (rest % b.x == 0 && presses_a * a.y + presses_b * b.y == prize.1)
+                .then_some(presses_a * A_PRICE + presses_b * B_PRICE)
+        })
+        .min()
+}
+
+/// Solves both equations with Cramer's rule in exact integers; too many
+/// presses are needed for a search once the prize moved.
+fn cheapest_by_algebra(game: &Game) -> Option<i64> {
+    let (a, b) = (&game.button_a, &game.button_b);
+    let (x, y) = (game.prize.0 as i128, game.prize.1 as i128);
+    let det = (a.x * b.y - a.y * b.x) as i128;
+    let presses_a = x * b.y as i128 - y * b.x as i128;
+    let presses_b = a.x as i128 * y - a.y as i128 * x;
+    if presses_a % det != 0 || presses_b % det != 0 {
+        return None;
+    }
+    let (presses_a, presses_b) = (presses_a / det, presses_b / det);
+    if presses_a < 0 || presses_b < 0 {
+        return None;
+    }
+    Some((presses_a * A_PRICE as i128 + presses_b * B_PRICE as i128) as i64)
+}
+
+impl Reference for Day13 {
+    /// Buttons move the claw up and right, and never along the same line.
+    fn is_valid(games: &Vec<Game>) -> bool {
+        games.iter().all(|game| {
+            let (a, b) = (&game.button_a, &game.button_b);
+            a.x > 0 && a.y > 0 && b.x > 0 && b.y > 0 && a.x * b.y != a.y * b.x
+        })
+    }
+
+    fn reference_part1(games: &Vec<Game>) -> Answer {
+        games
+            .iter()
+            .filter_map(cheapest_by_search)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn reference_part2(games: &Vec<Game>) -> Answer {
+        games
+            .iter()
+            .map(|game| Game {
+                prize: (game.prize.0 + AMOUNT_TO_ADD, game.prize.1 + AMOUNT_TO_ADD),
+                ..game.clone()
+            })
+            .filter_map(|game| cheapest_by_algebra(&game))
+            .sum::<i64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn solves_the_example() {
+        let games = Day13::parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day13::reference_part1(&games), Answer::Signed(480));
+        assert_eq!(Day13::reference_part2(&games), Answer::Signed(875318608908));
+        for game in &games {
+            assert_eq!(cheapest_by_search(game), cheapest_by_algebra(game));
+        }
+    }
+}
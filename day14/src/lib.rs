@@ -0,0 +1,247 @@
+use common::{Answer, ParseError, Point, Solution, SolveError, Source};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Robot {
+    position: Point<i32>,
+    velocity: Point<i32>,
+}
+
+/// The robots and the room they move in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lobby {
+    size: Point<i32>,
+    robots: Vec<Robot>,
+}
+
+/// The room of the puzzle input, used unless a first `size=W,H` line says
+/// otherwise.
+const SIZE: Point<i32> = Point::new(101, 103);
+
+fn read_robot(source: Source, line: &str) -> Result<Robot, ParseError> {
+    let mut parts = line.split_whitespace();
+    let pos_str = parts
+        .next()
+        .ok_or_else(|| source.error_after(line, "`p=`"))?;
+    let position = source.key_point(pos_str, "p")?;
+
+    let vel_str = parts
+        .next()
+        .ok_or_else(|| source.error_after(line, "`v=`"))?;
+    let velocity = source.key_point(vel_str, "v")?;
+
+    if let Some(extra) = parts.next() {
+        return Err(source.error(extra, "end of line"));
+    }
+    Ok(Robot { position, velocity })
+}
+
+fn read_input(source: Source, mut size: Point<i32>) -> Result<Lobby, ParseError> {
+    let mut lines = source.lines().peekable();
+    if let Some(line) = lines.next_if(|line| line.starts_with("size=")) {
+        size = source.key_point(line, "size")?;
+        if size.x <= 0 || size.y <= 0 {
+            return Err(source.error(line, "a room of positive size"));
+        }
+    }
+    let robots = lines
+        .map(|line| read_robot(source, line))
+        .collect::<Result<_, _>>()?;
+    Ok(Lobby { size, robots })
+}
+
+const SECONDS: i32 = 100;
+
+/// Robots teleport to the other side when they walk off an edge.
+fn move_robot(robot: &Robot, times: i32, size: Point<i32>) -> Point<i32> {
+    robot.position.wrapping_step(robot.velocity, times, size)
+}
+
+enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Middle, // Not a real quadrant, represents the middle of the grid
+}
+
+fn determine_quadrant(pos: &Point<i32>, size: Point<i32>) -> Quadrant {
+    let (mid_x, mid_y) = (size.x / 2, size.y / 2);
+    if pos.x < mid_x && pos.y < mid_y {
+        Quadrant::TopLeft
+    } else if pos.x > mid_x && pos.y < mid_y {
+        Quadrant::TopRight
+    } else if pos.x < mid_x && pos.y > mid_y {
+        Quadrant::BottomLeft
+    } else if pos.x > mid_x && pos.y > mid_y {
+        Quadrant::BottomRight
+    } else {
+        Quadrant::Middle
+    }
+}
+
+/// The product of the robot counts of the four quadrants, in `u64` since
+/// each count can be as large as the swarm.
+fn calculate_safety_factor(robots: &[Robot], size: Point<i32>) -> u64 {
+    let mut num_q1: u64 = 0;
+    let mut num_q2: u64 = 0;
+    let mut num_q3: u64 = 0;
+    let mut num_q4: u64 = 0;
+
+    robots
+        .iter()
+        .for_each(|robot| match determine_quadrant(&robot.position, size) {
+            Quadrant::TopLeft => num_q1 += 1,
+            Quadrant::TopRight => num_q2 += 1,
+            Quadrant::BottomLeft => num_q3 += 1,
+            Quadrant::BottomRight => num_q4 += 1,
+            Quadrant::Middle => (),
+        });
+    num_q1 * num_q2 * num_q3 * num_q4
+}
+
+fn part1(lobby: &Lobby) -> u64 {
+    let mut robots = lobby.robots.to_vec();
+    robots.iter_mut().for_each(|robot| {
+        robot.position = move_robot(robot, SECONDS, lobby.size);
+    });
+    calculate_safety_factor(&robots, lobby.size)
+}
+
+const MAGIC_NUMBER: i32 = 10000;
+
+fn part2(lobby: &Lobby) -> i32 {
+    let mut robots = lobby.robots.to_vec();
+    let mut min_safety_factor = u64::MAX;
+    let mut min_seconds = 0;
+    for i in 0..MAGIC_NUMBER {
+        for robot in robots.iter_mut() {
+            robot.position = move_robot(robot, 1, lobby.size);
+        }
+
+        let safety_factor = calculate_safety_factor(&robots, lobby.size);
+        if safety_factor < min_safety_factor {
+            min_safety_factor = safety_factor;
+            min_seconds = i + 1;
+        }
+    }
+    min_seconds
+}
+
+pub struct Day14;
+
+impl Day14 {
+    /// Reads robots moving in a room of `size` rather than the puzzle's,
+    /// such as the 11×7 room of the example.
+    pub fn with_size(input: &str, size: Point<i32>) -> Result<Lobby, ParseError> {
+        read_input(Source::new(Self::DAY, input), size)
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Lobby;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::with_size(input, SIZE)
+    }
+
+    fn part1(lobby: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(lobby).into())
+    }
+
+    fn part2(lobby: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(lobby).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> <Day14 as Solution>::Input {
+        Day14::with_size(EXAMPLE, Point::new(11, 7)).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&example()), 12);
+    }
+
+    #[test]
+    fn parses_example() {
+        let lobby = example();
+        assert_eq!(lobby.size, Point::new(11, 7));
+        assert_eq!(lobby.robots.len(), 12);
+        assert_eq!(lobby.robots[0].position, Point::new(0, 4));
+        assert_eq!(lobby.robots[0].velocity, Point::new(3, -3));
+    }
+
+    #[test]
+    fn the_room_defaults_to_the_puzzle_size() {
+        let lobby = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(lobby.size, SIZE);
+        assert_eq!(lobby.robots, example().robots);
+        let err = Day14::parse("size=0,7\np=0,4 v=3,-3\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (1, "a room of positive size")
+        );
+        let err = Day14::parse("size=11\n").unwrap_err();
+        assert_eq!(err.expected, "`,`");
+    }
+
+    #[test]
+    fn determine_quadrant_skips_the_middle_lines() {
+        let at = |x, y| determine_quadrant(&Point::new(x, y), SIZE);
+        assert!(matches!(at(0, 0), Quadrant::TopLeft));
+        assert!(matches!(at(SIZE.x - 1, 0), Quadrant::TopRight));
+        assert!(matches!(at(0, SIZE.y - 1), Quadrant::BottomLeft));
+        assert!(matches!(at(SIZE.x - 1, SIZE.y - 1), Quadrant::BottomRight));
+        assert!(matches!(at(SIZE.x / 2, 0), Quadrant::Middle));
+        assert!(matches!(at(0, SIZE.y / 2), Quadrant::Middle));
+    }
+
+    #[test]
+    fn move_robot_wraps_around_the_edges() {
+        let robot = Robot {
+            position: Point::new(2, 4),
+            velocity: Point::new(-3, -5),
+        };
+        assert_eq!(
+            move_robot(&robot, 1, SIZE),
+            Point::new(SIZE.x - 1, SIZE.y - 1)
+        );
+        assert_eq!(move_robot(&robot, SIZE.x * SIZE.y, SIZE), robot.position);
+    }
+
+    fn robot() -> impl Strategy<Value = Robot> {
+        (0..SIZE.x, 0..SIZE.y, -100..100i32, -100..100i32).prop_map(|(x, y, dx, dy)| Robot {
+            position: Point::new(x, y),
+            velocity: Point::new(dx, dy),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(robots in prop::collection::vec(robot(), 1..30)) {
+            let text: String = robots
+                .iter()
+                .map(|r| {
+                    format!(
+                        "p={},{} v={},{}\n",
+                        r.position.x, r.position.y, r.velocity.x, r.velocity.y
+                    )
+                })
+                .collect();
+            prop_assert_eq!(Day14::parse(&text).unwrap().robots, robots);
+        }
+
+        #[test]
+        fn parse_never_panics(text in "(size=|p=|v=|-|,| |[0-9]{1,3}|\n)*") {
+            let _ = Day14::parse(&text);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use common::Solution;
+use day05::Day05;
+use libfuzzer_sys::fuzz_target;
+
+// parsing arbitrary text may fail, but must never panic
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = Day05::parse(text);
+    }
+});
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Splits `nodes` into groups that are reachable from one another.
+///
+/// `successors` has to be symmetric (an undirected graph). Components come
+/// out in the order of their first node in `nodes`, each one in breadth-first
+/// order from that node.
+pub fn connected_components<N, FN, IN>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: FN,
+) -> Vec<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut component = vec![node];
+        let mut i = 0;
+        while i < component.len() {
+            for next in successors(&component[i]) {
+                if seen.insert(next.clone()) {
+                    component.push(next);
+                }
+            }
+            i += 1;
+        }
+        components.push(component);
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_an_undirected_graph() {
+        let edges = [(0, 1), (1, 2), (3, 4)];
+        let neighbours = |n: &u32| {
+            edges
+                .iter()
+                .filter_map(|&(a, b)| match n {
+                    _ if *n == a => Some(b),
+                    _ if *n == b => Some(a),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        let components = connected_components(0..6, neighbours);
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+}
@@ -0,0 +1,37 @@
+//! Generic searches over implicit graphs.
+//!
+//! Nothing is stored up front: every search takes a start node and a
+//! `successors` function returning the neighbours of a node (with their cost
+//! for the weighted searches), so the same code walks grids, puzzle states
+//! and adjacency maps alike.
+
+mod components;
+mod traverse;
+mod weighted;
+
+pub use components::connected_components;
+pub use traverse::{bfs, bfs_reach, count_paths, dfs, dfs_reach};
+pub use weighted::{astar, dijkstra};
+
+/// Rebuilds the path ending at `end` by following `parent` until a node
+/// without one (the start) is reached. The path is returned start first.
+pub fn reconstruct_path<N>(end: N, mut parent: impl FnMut(&N) -> Option<N>) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(previous) = parent(path.last().unwrap()) {
+        path.push(previous);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconstruct_path_starts_at_the_root() {
+        let parents = [None, Some(0), Some(1), Some(1)];
+        assert_eq!(reconstruct_path(3, |&n| parents[n]), vec![0, 1, 3]);
+        assert_eq!(reconstruct_path(0, |&n| parents[n]), vec![0]);
+    }
+}
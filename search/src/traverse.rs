@@ -0,0 +1,214 @@
+use crate::reconstruct_path;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Breadth-first search for the shortest path (in number of steps) from
+/// `start` to a node satisfying `success`, both ends included.
+pub fn bfs<N, FN, IN>(
+    start: N,
+    mut successors: FN,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if success(&node) {
+            return Some(reconstruct_path(node, |n| parents.get(n).cloned()));
+        }
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start` (included), in breadth-first order.
+pub fn bfs_reach<N, FN, IN>(start: N, mut successors: FN) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut order = vec![start];
+    let mut i = 0;
+
+    // the visit order doubles as the queue
+    while i < order.len() {
+        for next in successors(&order[i]) {
+            if seen.insert(next.clone()) {
+                order.push(next);
+            }
+        }
+        i += 1;
+    }
+    order
+}
+
+/// Depth-first search for any path from `start` to a node satisfying
+/// `success`, both ends included. The path is not necessarily the shortest.
+pub fn dfs<N, FN, IN>(
+    start: N,
+    mut successors: FN,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        if success(&node) {
+            return Some(reconstruct_path(node, |n| parents.get(n).cloned()));
+        }
+        for next in successors(&node) {
+            if !visited.contains(&next) {
+                // the last push is popped first, so it is the real parent
+                parents.insert(next.clone(), node.clone());
+                stack.push(next);
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start` (included), in depth-first order.
+pub fn dfs_reach<N, FN, IN>(start: N, mut successors: FN) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if visited.insert(node.clone()) {
+            stack.extend(
+                successors(&node)
+                    .into_iter()
+                    .filter(|n| !visited.contains(n)),
+            );
+            order.push(node);
+        }
+    }
+    order
+}
+
+/// Counts the distinct paths from `start` to nodes satisfying `success`.
+///
+/// The graph must be acyclic. A path stops at the first successful node, and
+/// the count of every node is cached, so shared sub-paths are only walked once.
+pub fn count_paths<N, FN, IN>(
+    start: N,
+    mut successors: FN,
+    mut success: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    fn count<N, FN, IN, FS>(
+        node: N,
+        successors: &mut FN,
+        success: &mut FS,
+        cache: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Eq + Hash + Clone,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+        FS: FnMut(&N) -> bool,
+    {
+        if success(&node) {
+            return 1;
+        }
+        if let Some(&known) = cache.get(&node) {
+            return known;
+        }
+        let next: Vec<N> = successors(&node).into_iter().collect();
+        let total = next
+            .into_iter()
+            .map(|n| count(n, successors, success, cache))
+            .sum();
+        cache.insert(node, total);
+        total
+    }
+
+    count(start, &mut successors, &mut success, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 → 1 → 3 → 5
+    // 0 → 2 → 3
+    // 2 → 4 → 5
+    fn successors(n: &u32) -> Vec<u32> {
+        match n {
+            0 => vec![1, 2],
+            1 => vec![3],
+            2 => vec![3, 4],
+            3 | 4 => vec![5],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        assert_eq!(bfs(0, successors, |&n| n == 5), Some(vec![0, 1, 3, 5]));
+        assert_eq!(bfs(0, successors, |&n| n == 0), Some(vec![0]));
+        assert_eq!(bfs(3, successors, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn dfs_finds_some_path() {
+        let path = dfs(0, successors, |&n| n == 5).unwrap();
+        assert_eq!((path[0], path[path.len() - 1]), (0, 5));
+        assert!(path.windows(2).all(|w| successors(&w[0]).contains(&w[1])));
+        assert_eq!(dfs(4, successors, |&n| n == 1), None);
+    }
+
+    #[test]
+    fn reach_visits_every_node_once() {
+        assert_eq!(bfs_reach(0, successors), vec![0, 1, 2, 3, 4, 5]);
+        let mut order = dfs_reach(0, successors);
+        assert_eq!(order[0], 0);
+        order.sort();
+        assert_eq!(order, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn reach_handles_cycles() {
+        let ring = |n: &u32| [(n + 1) % 4];
+        assert_eq!(bfs_reach(2, ring), vec![2, 3, 0, 1]);
+        assert_eq!(dfs_reach(2, ring), vec![2, 3, 0, 1]);
+    }
+
+    #[test]
+    fn count_paths_counts_every_route() {
+        assert_eq!(count_paths(0, successors, |&n| n == 5), 3);
+        assert_eq!(count_paths(0, successors, |&n| n == 3), 2);
+        assert_eq!(count_paths(5, successors, |&n| n == 0), 0);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::reconstruct_path;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A queued node, ordered so that the smallest estimate pops first from the
+/// max-heap.
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // on equal estimates prefer the node that got further
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Cheapest path from `start` to a node satisfying `success`, and its cost.
+///
+/// `successors` yields each neighbour with the cost of the step, and
+/// `heuristic` must never overestimate the remaining cost for the result to
+/// be optimal. The cost type's `Default` is used as zero.
+pub fn astar<N, C, FN, IN>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    // nodes are numbered in discovery order so the heap does not need `N: Ord`
+    let mut indices: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut best = vec![C::default()];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut heap = BinaryHeap::from([Entry {
+        estimate: heuristic(&nodes[0]),
+        cost: C::default(),
+        index: 0,
+    }]);
+
+    while let Some(Entry { cost, index, .. }) = heap.pop() {
+        if cost > best[index] {
+            // a cheaper way to this node was already expanded
+            continue;
+        }
+        if success(&nodes[index]) {
+            let path = reconstruct_path(index, |&i| parents[i]);
+            return Some((path.into_iter().map(|i| nodes[i].clone()).collect(), cost));
+        }
+        for (next, step) in successors(&nodes[index]) {
+            let next_cost = cost + step;
+            let next_index = match indices.get(&next) {
+                Some(&i) if best[i] <= next_cost => continue,
+                Some(&i) => {
+                    best[i] = next_cost;
+                    parents[i] = Some(index);
+                    i
+                }
+                None => {
+                    let i = nodes.len();
+                    indices.insert(next.clone(), i);
+                    nodes.push(next);
+                    best.push(next_cost);
+                    parents.push(Some(index));
+                    i
+                }
+            };
+            heap.push(Entry {
+                estimate: next_cost + heuristic(&nodes[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+/// Cheapest path from `start` to a node satisfying `success`, and its cost;
+/// A* without a heuristic.
+pub fn dijkstra<N, C, FN, IN>(
+    start: N,
+    successors: FN,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the direct edge 0 → 3 is more expensive than going around
+    fn successors(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_detours() {
+        assert_eq!(
+            dijkstra(0, successors, |&n| n == 3),
+            Some((vec![0, 1, 2, 3], 6))
+        );
+        assert_eq!(dijkstra(0, successors, |&n| n == 0), Some((vec![0], 0)));
+        assert_eq!(dijkstra(3, successors, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn astar_on_an_open_plane() {
+        let target = (3i32, -2i32);
+        let distance = |&(x, y): &(i32, i32)| ((x - target.0).abs() + (y - target.1).abs()) as u32;
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].map(|p| (p, 1u32))
+        };
+        let (path, cost) = astar((0, 0), neighbours, distance, |&p| p == target).unwrap();
+        assert_eq!(cost, 5);
+        assert_eq!(path.len(), 6);
+        assert_eq!(path[5], target);
+    }
+}